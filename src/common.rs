@@ -1,3 +1,5 @@
+use crate::std::{fmt, ops::Deref, str};
+
 pub const UPPER: [u8; 16] = *b"0123456789ABCDEF";
 pub const LOWER: [u8; 16] = *b"0123456789abcdef";
 
@@ -35,3 +37,717 @@ pub const SHL4_TABLE: &[u8; 256] = &{
         i += 1;
     }
 };
+
+/// The position of the first problem in input to [`try_parse_hex_detailed`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HexError {
+    /// The 1-based position in the input of the problem, counted the same way
+    /// as the positions in the parse errors of `VolumeId32` and `VolumeId64`.
+    pub index: usize,
+    /// The byte at `index`, or `None` if the input ended before it.
+    ///
+    /// The byte is a valid hex digit if the input was too long.
+    pub byte: Option<u8>,
+}
+
+/// Parses exactly `2 * N` hexadecimal digits, in either case, into `N` bytes.
+///
+/// Unlike the `parse` methods of `VolumeId32` and `VolumeId64`, this returns
+/// the position of the first problem directly, so it can back parsers of any
+/// width. Input that is too short reports the position just past its end, and
+/// input that is too long reports the first extra byte.
+///
+/// # Errors
+///
+/// This function will return an error if `s` isn't `2 * N` bytes long or
+/// contains a byte that isn't a hex digit.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::{HexError, try_parse_hex_detailed};
+/// assert_eq!(try_parse_hex_detailed::<2>(b"a1B2"), Ok([0xa1, 0xb2]));
+/// assert_eq!(
+///     try_parse_hex_detailed::<2>(b"a1x2"),
+///     Err(HexError { index: 3, byte: Some(b'x') }),
+/// );
+/// assert_eq!(
+///     try_parse_hex_detailed::<2>(b"a1b"),
+///     Err(HexError { index: 4, byte: None }),
+/// );
+/// ```
+pub const fn try_parse_hex_detailed<const N: usize>(s: &[u8]) -> Result<[u8; N], HexError> {
+    let mut buf = [0u8; N];
+    let mut i = 0;
+
+    while i < N * 2 {
+        if i >= s.len() {
+            return Err(HexError {
+                index: i + 1,
+                byte: None,
+            });
+        }
+
+        // We use `0xff` as a sentinel value to indicate
+        // an invalid hex character (like the letter `G`)
+        let nibble = HEX_TABLE[s[i] as usize];
+        if nibble == 0xff {
+            return Err(HexError {
+                index: i + 1,
+                byte: Some(s[i]),
+            });
+        }
+
+        buf[i / 2] |= if i % 2 == 0 {
+            SHL4_TABLE[nibble as usize]
+        } else {
+            nibble
+        };
+        i += 1;
+    }
+
+    if s.len() > N * 2 {
+        return Err(HexError {
+            index: N * 2 + 1,
+            byte: Some(s[N * 2]),
+        });
+    }
+
+    return Ok(buf);
+}
+
+/// A string format for a volume identifier that is chosen at runtime.
+///
+/// Used by the `display_as` methods of `VolumeId32` and `VolumeId64`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FormatKind {
+    /// Hexadecimal digits with no separators, like `6ddcf6da`.
+    Simple,
+    /// Groups of four hexadecimal digits separated by `-`, like `6ddc-f6da`.
+    Hyphenated,
+    /// Each byte as two hexadecimal digits separated by `:`, like `6d:dc:f6:da`.
+    Colon,
+    /// The usual format for the width: [`Hyphenated`] for `VolumeId32`,
+    /// matching the `XXXX-XXXX` form shown by Windows and Linux, and
+    /// [`Simple`] for `VolumeId64`, matching the form shown by NTFS tools.
+    ///
+    /// [`Hyphenated`]: #variant.Hyphenated
+    /// [`Simple`]: #variant.Simple
+    Auto,
+}
+
+/// The class of a single character of a volume identifier string.
+///
+/// Returned by `VolumeId32::classify_chars` so tools can highlight which
+/// characters of an input are invalid.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CharClass {
+    /// A hexadecimal digit, in either case.
+    HexDigit,
+    /// A `-` separator.
+    Separator,
+    /// Any other character.
+    Invalid,
+}
+
+/// The byte order a volume identifier was stored in.
+///
+/// Used by the `from_field` and `to_writer` methods of `VolumeId32` and
+/// `VolumeId64`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endianness {
+    /// Little-endian, the order used by FAT, exFAT and NTFS.
+    Little,
+    /// Big-endian.
+    Big,
+}
+
+/// The kind of volume identifier a string looks like, judged only by its
+/// length and whether it contains `-` separators.
+///
+/// Returned by [`classify_input`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InputClass {
+    /// 8 characters without separators, like `6ddcf6da`.
+    Volume32Simple,
+    /// 9 characters with a separator, like `6ddc-f6da`.
+    Volume32Hyphenated,
+    /// 16 characters without separators, like `6ddcf6dafdc2fd38`.
+    Volume64Simple,
+    /// 19 characters with separators, like `6ddc-f6da-fdc2-fd38`.
+    Volume64Hyphenated,
+    /// Anything else.
+    Unknown,
+}
+
+/// Behaviour shared by `VolumeId32` and `VolumeId64`, for code that is
+/// generic over the width of a volume identifier.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "id32", feature = "id64"))] {
+/// # use fat_volume_id::{VolumeIdentifier, id32::VolumeId32, id64::VolumeId64};
+/// fn describe<T: VolumeIdentifier>(id: T) -> &'static str {
+///     if id.is_nil() { "unset" } else { "set" }
+/// }
+///
+/// assert_eq!(describe(VolumeId32::nil()), "unset");
+/// assert_eq!(describe(VolumeId64::max()), "set");
+/// # }
+/// ```
+pub trait VolumeIdentifier: Copy + Eq {
+    /// The error returned when parsing fails.
+    type Error;
+
+    /// Parses a volume identifier from a string of hexadecimal digits.
+    fn parse(input: &str) -> Result<Self, Self::Error>;
+
+    /// A volume identifier with all zeros.
+    fn nil() -> Self;
+
+    /// A volume identifier with all ones.
+    fn max() -> Self;
+
+    /// Tests if the volume identifier is nil (all zeros).
+    fn is_nil(&self) -> bool {
+        *self == Self::nil()
+    }
+
+    /// Tests if the volume identifier is max (all ones).
+    fn is_max(&self) -> bool {
+        *self == Self::max()
+    }
+}
+
+/// Parses a volume identifier of type `T` from a string of hexadecimal
+/// digits.
+///
+/// This calls [`VolumeIdentifier::parse`], so code that is generic over the
+/// width of a volume identifier can pick the type with a turbofish.
+///
+/// # Errors
+///
+/// This function will return an error if `input` can't be parsed as a `T`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(all(feature = "id32", feature = "id64"))] {
+/// # use fat_volume_id::{parse_id, id32::VolumeId32, id64::VolumeId64};
+/// let volumeid32 = parse_id::<VolumeId32>("6ddc-f6da").unwrap();
+/// let volumeid64: VolumeId64 = parse_id("6ddcf6dafdc2fd38").unwrap();
+///
+/// assert_eq!(volumeid32.to_string(), "6ddcf6da");
+/// assert_eq!(volumeid64.to_string(), "6ddcf6dafdc2fd38");
+/// # }
+/// ```
+pub fn parse_id<T: VolumeIdentifier>(input: &str) -> Result<T, T::Error> {
+    T::parse(input)
+}
+
+/// 64-bit FNV-1a hash of `bytes`.
+pub const fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+
+    hash
+}
+
+/// IEEE CRC32 of `bytes`, using the reflected polynomial `0xEDB88320`.
+pub const fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        crc ^= bytes[i] as u32;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+
+    !crc
+}
+
+/// Returns the kind of volume identifier `s` looks like, without parsing it.
+///
+/// Only the length of `s` and the presence of `-` are checked, so this can
+/// give a hint, like "looks like an NTFS serial", while the user is still
+/// typing. The digits themselves are checked by the `parse` methods of
+/// `VolumeId32` and `VolumeId64`.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::{InputClass, classify_input};
+/// assert_eq!(classify_input("6ddc-f6da"), InputClass::Volume32Hyphenated);
+/// assert_eq!(classify_input("6ddcf6dafdc2fd38"), InputClass::Volume64Simple);
+/// assert_eq!(classify_input("6ddcf6d"), InputClass::Unknown);
+/// ```
+pub const fn classify_input(s: &str) -> InputClass {
+    let bytes = s.as_bytes();
+    let mut separators = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'-' {
+            separators += 1;
+        }
+        i += 1;
+    }
+
+    match (bytes.len(), separators) {
+        (8, 0) => InputClass::Volume32Simple,
+        (9, 1) => InputClass::Volume32Hyphenated,
+        (16, 0) => InputClass::Volume64Simple,
+        (19, 3) => InputClass::Volume64Hyphenated,
+        _ => InputClass::Unknown,
+    }
+}
+
+/// The URL and filename safe base64 alphabet from RFC 4648.
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes `bytes` as `M` characters of unpadded base64url.
+///
+/// `M` must be the number of characters needed for `N` bytes, so any bits of
+/// the last character past the end of `bytes` are zero.
+pub(crate) const fn base64url_encode<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
+    assert!(M == (N * 8).div_ceil(6));
+
+    let mut buf = [0u8; M];
+    let mut i = 0;
+
+    while i < M {
+        let bit = i * 6;
+        let hi = bytes[bit / 8] as u16;
+        let lo = if bit / 8 + 1 < N {
+            bytes[bit / 8 + 1] as u16
+        } else {
+            0
+        };
+        let sextet = (((hi << 8) | lo) >> (10 - bit % 8)) & 0x3f;
+
+        buf[i] = BASE64URL[sextet as usize];
+        i += 1;
+    }
+
+    buf
+}
+
+/// Decodes `N` bytes from unpadded base64url, which must be exactly as long as
+/// [`base64url_encode`] makes it.
+///
+/// Returns the index of the first character outside the alphabet, or `None`
+/// if the unused bits of the last character aren't zero.
+pub(crate) const fn base64url_decode<const N: usize>(s: &[u8]) -> Result<[u8; N], Option<usize>> {
+    assert!(s.len() == (N * 8).div_ceil(6));
+
+    let mut buf = [0u8; N];
+    let mut i = 0;
+
+    while i < s.len() {
+        let sextet = match s[i] {
+            b'A'..=b'Z' => s[i] - b'A',
+            b'a'..=b'z' => s[i] - b'a' + 26,
+            b'0'..=b'9' => s[i] - b'0' + 52,
+            b'-' => 62,
+            b'_' => 63,
+            _ => return Err(Some(i)),
+        } as u16;
+
+        let bit = i * 6;
+        let shifted = sextet << (10 - bit % 8);
+        buf[bit / 8] |= (shifted >> 8) as u8;
+        if bit / 8 + 1 < N {
+            buf[bit / 8 + 1] |= shifted as u8;
+        } else if shifted as u8 != 0 {
+            return Err(None);
+        }
+        i += 1;
+    }
+
+    return Ok(buf);
+}
+
+/// Strips a single matching pair of `{}`, `[]`, `()`, `"` or `'` from
+/// `input`.
+///
+/// Input without a wrapper is returned as-is. Returns `None` if the wrapper is
+/// mismatched or nested.
+pub(crate) fn strip_wrapper(input: &str) -> Option<&str> {
+    const PAIRS: [(u8, u8); 5] = [
+        (b'{', b'}'),
+        (b'[', b']'),
+        (b'(', b')'),
+        (b'"', b'"'),
+        (b'\'', b'\''),
+    ];
+
+    let is_opener = |b: Option<&u8>| PAIRS.iter().any(|&(open, _)| Some(&open) == b);
+    let is_closer = |b: Option<&u8>| PAIRS.iter().any(|&(_, close)| Some(&close) == b);
+
+    let bytes = input.as_bytes();
+    let inner = match PAIRS
+        .iter()
+        .find(|&&(open, _)| bytes.first() == Some(&open))
+    {
+        Some(&(_, close)) if bytes.len() >= 2 && bytes[bytes.len() - 1] == close => {
+            &input[1..input.len() - 1]
+        }
+        Some(_) => return None,
+        None if is_closer(bytes.last()) => return None,
+        None => return Some(input),
+    };
+
+    if is_opener(inner.as_bytes().first()) || is_closer(inner.as_bytes().last()) {
+        return None;
+    }
+
+    Some(inner)
+}
+
+/// Builds a filename like `prefix_serial.ext`, replacing any character of
+/// `prefix` or `ext` that isn't an ASCII letter, digit, `-` or `_` with `_`.
+///
+/// An empty `prefix` or `ext` is left out along with its separator, and a
+/// leading `.` on `ext` is ignored.
+#[cfg(feature = "alloc")]
+pub(crate) fn filename(prefix: &str, serial: &str, ext: &str) -> crate::alloc::string::String {
+    let sanitize = |c: char| {
+        if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            c
+        } else {
+            '_'
+        }
+    };
+    let ext = ext.strip_prefix('.').unwrap_or(ext);
+
+    let mut name =
+        crate::alloc::string::String::with_capacity(prefix.len() + serial.len() + ext.len() + 2);
+    if !prefix.is_empty() {
+        name.extend(prefix.chars().map(sanitize));
+        name.push('_');
+    }
+    name.push_str(serial);
+    if !ext.is_empty() {
+        name.push('.');
+        name.extend(ext.chars().map(sanitize));
+    }
+
+    name
+}
+
+/// Compares two ASCII byte slices, ignoring case.
+pub(crate) const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Writes `bytes` as hex digits to `f`, inserting `sep` after every `group`
+/// bytes and padding the result like a string.
+///
+/// `bytes` must be at most 8 bytes long.
+pub(crate) fn write_separated(
+    f: &mut fmt::Formatter<'_>,
+    bytes: &[u8],
+    group: usize,
+    sep: char,
+    upper: bool,
+) -> fmt::Result {
+    let digits = if upper { &UPPER } else { &LOWER };
+    let mut buf = [0u8; 48];
+    let mut len = 0;
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        if i > 0 && i % group == 0 {
+            len += sep.encode_utf8(&mut buf[len..]).len();
+        }
+        buf[len] = digits[(byte >> 4) as usize];
+        buf[len + 1] = digits[(byte & 0xf) as usize];
+        len += 2;
+    }
+
+    // SAFETY: `buf[..len]` only holds ASCII hex digits and encoded chars
+    f.pad(unsafe { str::from_utf8_unchecked(&buf[..len]) })
+}
+
+/// Reads `N` bytes starting at `byte_offset` from the hex columns of a
+/// hexdump line, like those produced by `xxd` or `hexdump -C`.
+///
+/// The first token of the line is the offset and is skipped. If it ends in
+/// `:`, as in `xxd`, the hex columns end at the double space before the text
+/// column. Otherwise, as in `hexdump -C`, they end at the `|` of the text
+/// column or after 16 bytes. Within those columns, reading also stops at the
+/// first token that isn't an even number of hex digits. On failure, returns the
+/// number of bytes the line contained.
+pub(crate) fn hexdump_bytes<const N: usize>(
+    line: &str,
+    byte_offset: usize,
+) -> Result<[u8; N], usize> {
+    let mut dst = [0u8; N];
+    let mut pos = 0;
+
+    let line = line.trim_start();
+    let (offset, rest) = line.split_at(line.find(char::is_whitespace).unwrap_or(line.len()));
+    let (columns, max_len) = if offset.ends_with(':') {
+        let rest = rest.trim_start();
+        (rest.split("  ").next().unwrap_or(rest), usize::MAX)
+    } else {
+        (rest.split('|').next().unwrap_or(rest), 16)
+    };
+
+    let tokens = columns
+        .split_ascii_whitespace()
+        .take_while(|token| token.len() % 2 == 0 && token.bytes().all(|b| b.is_ascii_hexdigit()));
+
+    for token in tokens {
+        for pair in token.as_bytes().chunks(2) {
+            if pos >= max_len {
+                break;
+            }
+            if pos >= byte_offset && pos - byte_offset < N {
+                dst[pos - byte_offset] =
+                    SHL4_TABLE[HEX_TABLE[pair[0] as usize] as usize] | HEX_TABLE[pair[1] as usize];
+            }
+            pos += 1;
+        }
+    }
+
+    if pos < byte_offset.saturating_add(N) {
+        return Err(pos);
+    }
+
+    Ok(dst)
+}
+
+/// An owned string of at most `N` ASCII characters, stored inline.
+///
+/// This is returned by methods like `VolumeId32::simple_str` to give `no_std`
+/// users an owned, printable string without needing an allocator or managing
+/// a buffer. It dereferences to [`prim@str`].
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct IdStr<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> IdStr<N> {
+    /// Creates an `IdStr` from the first `len` bytes of `buf`, which must be
+    /// ASCII.
+    pub(crate) const fn new(buf: [u8; N], len: usize) -> Self {
+        assert!(len <= N && buf.is_ascii());
+        IdStr { buf, len }
+    }
+
+    /// Returns the contents of the `IdStr` as a string slice.
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.buf.split_at(self.len);
+
+        // SAFETY: `new` only accepts ASCII bytes
+        unsafe { str::from_utf8_unchecked(bytes) }
+    }
+}
+
+impl<const N: usize> Deref for IdStr<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for IdStr<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<str> for IdStr<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for IdStr<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> fmt::Debug for IdStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for IdStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.byte {
+            Some(byte) => write!(
+                f,
+                "invalid hex input: unexpected byte {:#04x} at {}",
+                byte, self.index
+            ),
+            None => write!(f, "invalid hex input: input ended at {}", self.index),
+        }
+    }
+}
+
+impl crate::std::error::Error for HexError {}
+
+#[cfg(all(test, feature = "id32", feature = "id64"))]
+mod tests {
+    use super::*;
+    use crate::{id32::VolumeId32, id64::VolumeId64};
+
+    fn nil_and_max<T: VolumeIdentifier>() -> [bool; 4] {
+        [
+            T::nil().is_nil(),
+            T::nil().is_max(),
+            T::max().is_nil(),
+            T::max().is_max(),
+        ]
+    }
+
+    fn parse_nil_and_max<T: VolumeIdentifier>(nil: &str, max: &str) -> bool {
+        match (parse_id::<T>(nil), parse_id::<T>(max)) {
+            (Ok(nil), Ok(max)) => nil.is_nil() && max.is_max(),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_parse_id() {
+        assert!(parse_nil_and_max::<VolumeId32>("00000000", "ffff-ffff"));
+        assert!(parse_nil_and_max::<VolumeId64>(
+            "0000000000000000",
+            "ffffffffffffffff"
+        ));
+
+        assert!(!parse_nil_and_max::<VolumeId32>(
+            "0000000000000000",
+            "ffffffff"
+        ));
+        assert!(parse_id::<VolumeId64>("00000000").is_err());
+        assert_eq!(
+            parse_id::<VolumeId32>("6ddcf6da"),
+            VolumeId32::parse("6ddcf6da")
+        );
+    }
+
+    #[test]
+    fn test_volume_identifier_nil_max() {
+        assert_eq!(nil_and_max::<VolumeId32>(), [true, false, false, true]);
+        assert_eq!(nil_and_max::<VolumeId64>(), [true, false, false, true]);
+
+        assert!(!VolumeIdentifier::is_nil(&VolumeId32::from_u32(1)));
+        assert!(!VolumeIdentifier::is_max(&VolumeId64::from_u64(1)));
+    }
+
+    #[test]
+    fn test_classify_input() {
+        assert_eq!(classify_input("6ddcf6da"), InputClass::Volume32Simple);
+        assert_eq!(classify_input("6ddc-f6da"), InputClass::Volume32Hyphenated);
+        assert_eq!(
+            classify_input("6ddcf6dafdc2fd38"),
+            InputClass::Volume64Simple
+        );
+        assert_eq!(
+            classify_input("6ddc-f6da-fdc2-fd38"),
+            InputClass::Volume64Hyphenated
+        );
+
+        assert_eq!(classify_input(""), InputClass::Unknown);
+        assert_eq!(classify_input("6ddcf6d"), InputClass::Unknown);
+        assert_eq!(classify_input("6ddcf6dab"), InputClass::Unknown);
+        assert_eq!(classify_input("6d-dc-f6da"), InputClass::Unknown);
+        assert_eq!(classify_input("6ddcf6dafdc2fd38aa-"), InputClass::Unknown);
+    }
+
+    #[test]
+    fn test_try_parse_hex_detailed() {
+        assert_eq!(
+            try_parse_hex_detailed::<4>(b"a1a2a3a4"),
+            Ok([0xa1, 0xa2, 0xa3, 0xa4])
+        );
+        assert_eq!(
+            try_parse_hex_detailed::<8>(b"6DDCF6DAfdc2fd38"),
+            Ok([0x6d, 0xdc, 0xf6, 0xda, 0xfd, 0xc2, 0xfd, 0x38])
+        );
+        assert_eq!(
+            try_parse_hex_detailed::<16>(b"000102030405060708090a0b0c0d0e0f"),
+            Ok([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])
+        );
+        assert_eq!(try_parse_hex_detailed::<0>(b""), Ok([]));
+
+        assert_eq!(
+            try_parse_hex_detailed::<4>(b"a1a2g3a4"),
+            Err(HexError {
+                index: 5,
+                byte: Some(b'g'),
+            })
+        );
+        assert_eq!(
+            try_parse_hex_detailed::<8>(b"6ddcf6dafdc2fd3-"),
+            Err(HexError {
+                index: 16,
+                byte: Some(b'-'),
+            })
+        );
+        assert_eq!(
+            try_parse_hex_detailed::<4>(b"a1a2"),
+            Err(HexError {
+                index: 5,
+                byte: None,
+            })
+        );
+        assert_eq!(
+            try_parse_hex_detailed::<4>(b"a1a2a3a4a5"),
+            Err(HexError {
+                index: 9,
+                byte: Some(b'a'),
+            })
+        );
+
+        // The first problem is reported, even if the input is also too long.
+        assert_eq!(
+            try_parse_hex_detailed::<4>(b"xxa2a3a4a5"),
+            Err(HexError {
+                index: 1,
+                byte: Some(b'x'),
+            })
+        );
+    }
+}
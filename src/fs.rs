@@ -0,0 +1,446 @@
+#[cfg(feature = "id32")]
+use crate::id32::{Error, ErrorKind, VolumeId32};
+
+/// Offset of the volume serial number in a FAT32 boot sector.
+#[cfg(feature = "id32")]
+pub(crate) const FAT32_SERIAL_OFFSET: usize = 0x43;
+
+/// Offset of the volume serial number in a FAT12/16 boot sector.
+#[cfg(feature = "id32")]
+pub(crate) const FAT16_SERIAL_OFFSET: usize = 0x27;
+
+/// Offset of the volume serial number in an exFAT boot sector.
+#[cfg(feature = "id32")]
+pub(crate) const EXFAT_SERIAL_OFFSET: usize = 0x64;
+
+/// Offset of the volume serial number in an NTFS boot sector.
+#[cfg(feature = "id64")]
+pub(crate) const NTFS_SERIAL_OFFSET: usize = 0x48;
+
+/// A filesystem that stores a 32-bit volume serial number in its boot sector.
+#[cfg(feature = "id32")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FatKind {
+    /// FAT12 or FAT16, with the serial at offset `0x27`.
+    Fat16,
+    /// FAT32, with the serial at offset `0x43`.
+    Fat32,
+    /// exFAT, with the serial at offset `0x64`.
+    ExFat,
+}
+
+#[cfg(feature = "id32")]
+impl FatKind {
+    /// Returns the offset of the volume serial number in the boot sector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::fs::FatKind;
+    /// assert_eq!(FatKind::Fat32.serial_offset(), 0x43);
+    /// ```
+    pub const fn serial_offset(self) -> usize {
+        match self {
+            FatKind::Fat16 => FAT16_SERIAL_OFFSET,
+            FatKind::Fat32 => FAT32_SERIAL_OFFSET,
+            FatKind::ExFat => EXFAT_SERIAL_OFFSET,
+        }
+    }
+}
+
+/// Computes the `SetChecksum` of an exFAT directory entry set.
+///
+/// `entries` must contain the whole entry set, starting with its primary
+/// directory entry. The checksum field itself, at offsets 2 and 3 of the
+/// primary entry, is skipped so the checksum of a set can be verified
+/// without clearing it first.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::fs::entry_set_checksum;
+/// let mut entries = [0u8; 64];
+/// entries[0] = 0x85;
+/// entries[32] = 0xc0;
+///
+/// let checksum = entry_set_checksum(&entries);
+/// entries[2..4].copy_from_slice(&checksum.to_le_bytes());
+///
+/// assert_eq!(entry_set_checksum(&entries), checksum);
+/// ```
+pub const fn entry_set_checksum(entries: &[u8]) -> u16 {
+    let mut checksum: u16 = 0;
+    let mut i = 0;
+
+    while i < entries.len() {
+        if i != 2 && i != 3 {
+            checksum = checksum.rotate_right(1).wrapping_add(entries[i] as u16);
+        }
+        i += 1;
+    }
+
+    return checksum;
+}
+
+/// Computes the checksum of an exFAT main or backup boot region.
+///
+/// `region` must start with the boot sector and contain at least the 12
+/// sectors of the boot region. The checksum covers the first 11 sectors,
+/// skipping the `VolumeFlags` and `PercentInUse` fields of the boot sector,
+/// and should be repeated throughout the 12th sector.
+///
+/// # Errors
+///
+/// This function will return an error if the bytes per sector shift of the
+/// boot sector isn't between 9 and 12, or if `region` is shorter than 12
+/// sectors.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::fs::{exfat_boot_checksum, exfat_fix_boot_checksum};
+/// let mut region = [0u8; 12 * 512];
+/// region[108] = 9;
+///
+/// let checksum = exfat_fix_boot_checksum(&mut region).unwrap();
+///
+/// assert_eq!(exfat_boot_checksum(&region), Ok(checksum));
+/// assert_eq!(region[11 * 512..11 * 512 + 4], checksum.to_le_bytes());
+/// ```
+#[cfg(feature = "id32")]
+pub fn exfat_boot_checksum(region: &[u8]) -> Result<u32, Error> {
+    let sector_size = exfat_sector_size(region)?;
+    let mut checksum: u32 = 0;
+
+    for (i, &byte) in region[..11 * sector_size].iter().enumerate() {
+        if i != 106 && i != 107 && i != 112 {
+            checksum = checksum.rotate_right(1).wrapping_add(byte as u32);
+        }
+    }
+
+    return Ok(checksum);
+}
+
+/// Recomputes the checksum of an exFAT boot region and writes it into the
+/// checksum sector, returning the new checksum.
+///
+/// Tools that change the serial number of an exFAT volume must call this, or
+/// the boot region will fail validation. See [`exfat_boot_checksum`] for the
+/// layout of `region`.
+///
+/// # Errors
+///
+/// This function will return an error if the bytes per sector shift of the
+/// boot sector isn't between 9 and 12, or if `region` is shorter than 12
+/// sectors.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::{fs::{exfat_boot_checksum, exfat_fix_boot_checksum}, id32::VolumeId32};
+/// let mut region = [0u8; 12 * 512];
+/// region[108] = 9;
+/// exfat_fix_boot_checksum(&mut region).unwrap();
+///
+/// region[0x64..0x68].copy_from_slice(&VolumeId32::from_u32(0x6ddcf6da).to_bpb_field());
+/// let checksum = exfat_fix_boot_checksum(&mut region).unwrap();
+///
+/// assert_eq!(exfat_boot_checksum(&region), Ok(checksum));
+/// ```
+#[cfg(feature = "id32")]
+pub fn exfat_fix_boot_checksum(region: &mut [u8]) -> Result<u32, Error> {
+    let checksum = exfat_boot_checksum(region)?;
+    let sector_size = exfat_sector_size(region)?;
+
+    for field in region[11 * sector_size..12 * sector_size].chunks_exact_mut(4) {
+        field.copy_from_slice(&checksum.to_le_bytes());
+    }
+
+    return Ok(checksum);
+}
+
+/// Returns the sector size of an exFAT boot region, checking that `region`
+/// holds all 12 sectors.
+#[cfg(feature = "id32")]
+fn exfat_sector_size(region: &[u8]) -> Result<usize, Error> {
+    let shift = match region.get(108) {
+        Some(&shift) => shift,
+        None => {
+            return Err(Error(ErrorKind::BootSectorLength {
+                len: region.len(),
+                expected: 12 * 512,
+            }));
+        }
+    };
+    if !(9..=12).contains(&shift) {
+        return Err(Error(ErrorKind::SectorShift { shift }));
+    }
+
+    let sector_size = 1 << shift;
+    if region.len() < 12 * sector_size {
+        return Err(Error(ErrorKind::BootSectorLength {
+            len: region.len(),
+            expected: 12 * sector_size,
+        }));
+    }
+
+    return Ok(sector_size);
+}
+
+/// Tests if the serial number of a FAT32 boot sector matches the one in its
+/// backup boot sector.
+///
+/// FAT32 keeps a copy of the boot sector, normally in sector 6, which should
+/// hold the same serial number as the primary. A mismatch is a sign that one
+/// of the two copies is damaged or was edited on its own.
+///
+/// # Errors
+///
+/// This function will return an error if either boot sector is too short to
+/// contain the serial number field.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::fs::fat32_serial_matches_backup;
+/// let mut bs = [0u8; 512];
+/// bs[0x43..0x47].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+/// let backup_bs = bs;
+///
+/// assert_eq!(fat32_serial_matches_backup(&bs, &backup_bs), Ok(true));
+/// ```
+#[cfg(feature = "id32")]
+pub fn fat32_serial_matches_backup(bs: &[u8], backup_bs: &[u8]) -> Result<bool, Error> {
+    Ok(read_serial(bs, FAT32_SERIAL_OFFSET)? == read_serial(backup_bs, FAT32_SERIAL_OFFSET)?)
+}
+
+/// Returns the serial number the backup of a FAT32 boot sector should hold.
+///
+/// The backup boot sector is a copy of the primary, so this is the serial of
+/// `primary_bs`. Repair tools can write it into a damaged backup, and check
+/// the result with [`fat32_serial_matches_backup`].
+///
+/// # Errors
+///
+/// This function will return an error if `primary_bs` is too short to
+/// contain the serial number field.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::fs::expected_backup_serial;
+/// let mut bs = [0u8; 512];
+/// bs[0x43..0x47].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+///
+/// let serial = expected_backup_serial(&bs).unwrap();
+///
+/// assert_eq!(serial.as_u32(), 0x6ddcf6da);
+/// ```
+#[cfg(feature = "id32")]
+pub fn expected_backup_serial(primary_bs: &[u8]) -> Result<VolumeId32, Error> {
+    read_serial(primary_bs, FAT32_SERIAL_OFFSET)
+}
+
+/// Reads the serial number at the offset of each [`FatKind`] from a boot
+/// sector.
+///
+/// This is for images whose filesystem is unknown or ambiguous, so a tool can
+/// present every plausible serial to the user. Offsets that lie beyond the end
+/// of `bs` are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::fs::{FatKind, candidate_serials};
+/// let mut bs = [0u8; 512];
+/// bs[0x43..0x47].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+///
+/// let candidates = candidate_serials(&bs);
+///
+/// assert_eq!(candidates.len(), 3);
+/// assert_eq!(candidates[1].0, FatKind::Fat32);
+/// assert_eq!(candidates[1].1.as_u32(), 0x6ddcf6da);
+/// ```
+#[cfg(all(feature = "id32", feature = "alloc"))]
+pub fn candidate_serials(bs: &[u8]) -> crate::alloc::vec::Vec<(FatKind, VolumeId32)> {
+    [FatKind::Fat16, FatKind::Fat32, FatKind::ExFat]
+        .into_iter()
+        .filter_map(|fs| Some((fs, read_serial(bs, fs.serial_offset()).ok()?)))
+        .collect()
+}
+
+#[cfg(feature = "id32")]
+fn read_serial(bs: &[u8], offset: usize) -> Result<VolumeId32, Error> {
+    match bs.get(offset..offset + 4) {
+        Some(field) => VolumeId32::from_slice(field),
+        None => Err(Error(ErrorKind::BootSectorLength {
+            len: bs.len(),
+            expected: offset + 4,
+        })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_set_checksum() {
+        // A file directory entry set for `a.txt`: a file entry, a stream
+        // extension entry and a single file name entry.
+        let mut entries = [0u8; 96];
+        entries[0] = 0x85;
+        entries[1] = 2;
+        entries[4] = 0x20;
+
+        entries[32] = 0xc0;
+        entries[33] = 0x03;
+        entries[35] = 5;
+        entries[36..38].copy_from_slice(&[0x12, 0x34]);
+        entries[40] = 0x0c;
+        entries[52] = 0x05;
+        entries[56] = 0x0c;
+
+        entries[64] = 0xc1;
+        for (i, c) in "a.txt".bytes().enumerate() {
+            entries[66 + i * 2] = c;
+        }
+
+        assert_eq!(entry_set_checksum(&entries), 0x1e53);
+
+        // The checksum field does not contribute to the checksum.
+        entries[2..4].copy_from_slice(&0x1e53u16.to_le_bytes());
+        assert_eq!(entry_set_checksum(&entries), 0x1e53);
+
+        assert_eq!(entry_set_checksum(&[]), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "id32")]
+    fn test_fat32_serial_matches_backup() {
+        let mut bs = [0u8; 512];
+        bs[0x43..0x47].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+        let mut backup_bs = bs;
+
+        assert_eq!(fat32_serial_matches_backup(&bs, &backup_bs), Ok(true));
+
+        backup_bs[0x45] = 0x00;
+        assert_eq!(fat32_serial_matches_backup(&bs, &backup_bs), Ok(false));
+
+        assert_eq!(
+            fat32_serial_matches_backup(&bs, &backup_bs[..0x46]),
+            Err(Error(ErrorKind::BootSectorLength {
+                len: 0x46,
+                expected: 0x47,
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "id32")]
+    fn test_expected_backup_serial() {
+        let mut bs = [0u8; 512];
+        bs[0x43..0x47].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+
+        let serial = expected_backup_serial(&bs).unwrap();
+        assert_eq!(serial, VolumeId32::from_u32(0x6ddcf6da));
+
+        let mut backup_bs = [0u8; 512];
+        backup_bs[0x43..0x47].copy_from_slice(serial.as_bytes());
+        assert_eq!(fat32_serial_matches_backup(&bs, &backup_bs), Ok(true));
+
+        assert_eq!(
+            expected_backup_serial(&bs[..0x40]),
+            Err(Error(ErrorKind::BootSectorLength {
+                len: 0x40,
+                expected: 0x47,
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "id32", feature = "alloc"))]
+    fn test_candidate_serials() {
+        let mut bs = [0u8; 512];
+        bs[0x27..0x2b].copy_from_slice(&[0x11, 0x22, 0x33, 0x44]);
+        bs[0x43..0x47].copy_from_slice(&[0x55, 0x66, 0x77, 0x88]);
+        bs[0x64..0x68].copy_from_slice(&[0x99, 0xaa, 0xbb, 0xcc]);
+
+        assert_eq!(
+            candidate_serials(&bs),
+            [
+                (FatKind::Fat16, VolumeId32::from_u32(0x44332211)),
+                (FatKind::Fat32, VolumeId32::from_u32(0x88776655)),
+                (FatKind::ExFat, VolumeId32::from_u32(0xccbbaa99)),
+            ]
+        );
+
+        assert_eq!(
+            candidate_serials(&bs[..0x50]),
+            [
+                (FatKind::Fat16, VolumeId32::from_u32(0x44332211)),
+                (FatKind::Fat32, VolumeId32::from_u32(0x88776655)),
+            ]
+        );
+        assert!(candidate_serials(&bs[..0x20]).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "id32")]
+    fn test_exfat_fix_boot_checksum() {
+        let mut region = [0u8; 12 * 512];
+        region[..3].copy_from_slice(&[0xeb, 0x76, 0x90]);
+        region[3..11].copy_from_slice(b"EXFAT   ");
+        region[0x64..0x68].copy_from_slice(&[0x11, 0x22, 0x33, 0x44]);
+        region[108] = 9;
+        region[510..512].copy_from_slice(&[0x55, 0xaa]);
+
+        let old = exfat_fix_boot_checksum(&mut region).unwrap();
+        assert_eq!(exfat_boot_checksum(&region), Ok(old));
+
+        // Changing the serial invalidates the stored checksum.
+        region[0x64..0x68].copy_from_slice(&VolumeId32::from_u32(0x6ddcf6da).to_bpb_field());
+        let new = exfat_boot_checksum(&region).unwrap();
+        assert_ne!(new, old);
+        assert_eq!(region[11 * 512..11 * 512 + 4], old.to_le_bytes());
+
+        assert_eq!(exfat_fix_boot_checksum(&mut region), Ok(new));
+        for field in region[11 * 512..].chunks_exact(4) {
+            assert_eq!(field, new.to_le_bytes());
+        }
+
+        // VolumeFlags and PercentInUse don't contribute to the checksum.
+        region[106] = 0x02;
+        region[112] = 50;
+        assert_eq!(exfat_boot_checksum(&region), Ok(new));
+    }
+
+    #[test]
+    #[cfg(feature = "id32")]
+    fn test_exfat_boot_checksum_errors() {
+        let mut region = [0u8; 12 * 4096];
+
+        region[108] = 8;
+        assert_eq!(
+            exfat_boot_checksum(&region),
+            Err(Error(ErrorKind::SectorShift { shift: 8 }))
+        );
+
+        region[108] = 12;
+        assert_eq!(
+            exfat_fix_boot_checksum(&mut region[..12 * 512]),
+            Err(Error(ErrorKind::BootSectorLength {
+                len: 12 * 512,
+                expected: 12 * 4096,
+            }))
+        );
+        assert_eq!(
+            exfat_boot_checksum(&region[..100]),
+            Err(Error(ErrorKind::BootSectorLength {
+                len: 100,
+                expected: 12 * 512,
+            }))
+        );
+        assert!(exfat_boot_checksum(&region).is_ok());
+    }
+}
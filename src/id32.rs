@@ -1,11 +1,16 @@
 mod error;
 pub mod fmt;
+#[cfg(feature = "schemars")]
+mod json_schema;
 mod parser;
 #[cfg(feature = "serde")]
 pub mod serde;
 
-use crate::id32::error::ErrorKind;
-pub use crate::id32::error::{Error, InvalidVolumeId32};
+pub(crate) use crate::id32::error::ErrorKind;
+pub use crate::id32::{
+    error::{Error, InvalidVolumeId32},
+    parser::VolumeId32Parser,
+};
 
 /// 32-bit Volume ID used in FAT12/16/32 and exFAT filesystems.
 ///
@@ -48,6 +53,26 @@ pub use crate::id32::error::{Error, InvalidVolumeId32};
 pub struct VolumeId32([u8; 4]);
 
 impl VolumeId32 {
+    /// The simple string of [`nil`], `00000000`.
+    ///
+    /// [`nil`]: #method.nil
+    pub const NIL_STR: &'static str = "00000000";
+
+    /// The simple string of [`max`], `ffffffff`.
+    ///
+    /// [`max`]: #method.max
+    pub const MAX_STR: &'static str = "ffffffff";
+
+    /// The hyphenated string of [`nil`], `0000-0000`.
+    ///
+    /// [`nil`]: #method.nil
+    pub const NIL_HYPHENATED_STR: &'static str = "0000-0000";
+
+    /// The hyphenated string of [`max`], `ffff-ffff`.
+    ///
+    /// [`max`]: #method.max
+    pub const MAX_HYPHENATED_STR: &'static str = "ffff-ffff";
+
     /// A VolumeId32 with all zeros.
     /// Basic usage:
     ///
@@ -190,6 +215,50 @@ impl VolumeId32 {
         return Ok(VolumeId32::from_bytes_be(bytes));
     }
 
+    /// Creates a VolumeId32 using the supplied bytes, usable in const context.
+    ///
+    /// This is the same as [`from_slice`], but copies the bytes with a loop
+    /// so that it can be evaluated at compile time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `b` has any length other than 4.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// const VOLUMEID32: VolumeId32 = match VolumeId32::from_slice_const(&[
+    ///     0xa1, 0xa2, 0xa3, 0xa4,
+    /// ]) {
+    ///     Ok(v) => v,
+    ///     Err(_) => panic!(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4",
+    ///     VOLUMEID32.to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`from_slice`]: #method.from_slice
+    pub const fn from_slice_const(b: &[u8]) -> Result<Self, Error> {
+        if b.len() != 4 {
+            return Err(Error(ErrorKind::ParseByteLength { len: b.len() }));
+        }
+
+        let mut bytes = [0u8; 4];
+        let mut i = 0;
+        while i < 4 {
+            bytes[i] = b[i];
+            i += 1;
+        }
+
+        return Ok(VolumeId32::from_bytes(bytes));
+    }
+
     /// Creates a VolumeId32 from a 32bit value.
     ///
     /// # Examples
@@ -207,7 +276,7 @@ impl VolumeId32 {
     ///     volumeid32.hyphenated().to_string(),
     /// );
     /// ```
-    pub fn from_u32(v: u32) -> Self {
+    pub const fn from_u32(v: u32) -> Self {
         VolumeId32::from_bytes(v.to_le_bytes())
     }
 
@@ -232,7 +301,7 @@ impl VolumeId32 {
     ///     volumeid32.hyphenated().to_string(),
     /// );
     /// ```
-    pub fn from_u32_be(v: u32) -> Self {
+    pub const fn from_u32_be(v: u32) -> Self {
         VolumeId32::from_bytes(v.to_be_bytes())
     }
 
@@ -256,12 +325,11 @@ impl VolumeId32 {
         u32::from_le_bytes(*self.as_bytes())
     }
 
-    /// Returns a 32bit big-endian value containing the value.
+    /// Returns the integer you read off the displayed VolumeId32, so
+    /// `a1a2a3a4` gives `0xa1a2a3a4`.
     ///
-    /// The bytes in the `u32` will be flipped to convert into little-endian
-    /// order. This is based on the endianness of the VolumeId32, rather than the
-    /// target environment so bytes will be flipped on both big and little
-    /// endian machines.
+    /// This reads the stored bytes as big-endian, so it is the byte-swapped
+    /// value of [`as_u32`] on every target.
     ///
     /// # Examples
     ///
@@ -271,14 +339,26 @@ impl VolumeId32 {
     /// .unwrap();
     ///
     /// assert_eq!(
-    ///     volumeid32.as_u32_be(),
+    ///     volumeid32.display_value(),
     ///     0xa1a2a3a4,
     /// );
     /// ```
-    pub const fn as_u32_be(&self) -> u32 {
+    ///
+    /// [`as_u32`]: #method.as_u32
+    pub const fn display_value(&self) -> u32 {
         u32::from_be_bytes(*self.as_bytes())
     }
 
+    /// Returns a 32bit big-endian value containing the value.
+    ///
+    /// This is the same as [`display_value`].
+    ///
+    /// [`display_value`]: #method.display_value
+    #[deprecated(since = "0.3.1", note = "use `display_value` instead")]
+    pub const fn as_u32_be(&self) -> u32 {
+        self.display_value()
+    }
+
     /// Creates a VolumeId32 from two 16bit values.
     ///
     /// # Examples
@@ -297,7 +377,7 @@ impl VolumeId32 {
     ///     volumeid32.hyphenated().to_string(),
     /// );
     /// ```
-    pub fn from_u16_pair(hi: u16, lo: u16) -> Self {
+    pub const fn from_u16_pair(hi: u16, lo: u16) -> Self {
         VolumeId32::from_u32(((hi as u32) << 16) | lo as u32)
     }
 
@@ -319,7 +399,7 @@ impl VolumeId32 {
     ///     volumeid32.hyphenated().to_string(),
     /// );
     /// ```
-    pub fn from_u16_pair_be(hi: u16, lo: u16) -> Self {
+    pub const fn from_u16_pair_be(hi: u16, lo: u16) -> Self {
         VolumeId32::from_u32_be(((hi as u32) << 16) | lo as u32)
     }
 
@@ -387,6 +467,23 @@ impl VolumeId32 {
         [self.0[3], self.0[2], self.0[1], self.0[0]]
     }
 
+    /// Tests if the VolumeId32 equals the integer `v`, as returned by [`as_u32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// const VOLUMEID32: VolumeId32 = VolumeId32::from_u32(0xa4a3a2a1);
+    /// const _: () = assert!(VOLUMEID32.eq_u32(0xa4a3a2a1));
+    ///
+    /// assert_eq!(VOLUMEID32.to_string(), "a1a2a3a4");
+    /// ```
+    ///
+    /// [`as_u32`]: #method.as_u32
+    pub const fn eq_u32(&self, v: u32) -> bool {
+        self.as_u32() == v
+    }
+
     /// Tests if the VolumeId32 is nil (all zeros).
     pub const fn is_nil(&self) -> bool {
         self.as_u32() == u32::MIN
@@ -396,54 +493,2279 @@ impl VolumeId32 {
     pub const fn is_max(&self) -> bool {
         self.as_u32() == u32::MAX
     }
-}
 
-impl crate::std::hash::Hash for VolumeId32 {
-    fn hash<H: crate::std::hash::Hasher>(&self, state: &mut H) {
-        state.write(&self.0);
+    /// Tests if two VolumeId32s share the same leading `prefix_bits` bits of
+    /// their displayed value.
+    ///
+    /// This is useful when deciding how many hex digits must be shown to tell
+    /// two serials apart, much like abbreviated git hashes. A `prefix_bits`
+    /// of 0 always collides, while values of 32 or more compare the whole
+    /// serial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let a = VolumeId32::parse("a1a2a3a4").unwrap();
+    /// let b = VolumeId32::parse("a1a2b3b4").unwrap();
+    ///
+    /// assert!(a.short_collides(&b, 16));
+    /// assert!(!a.short_collides(&b, 32));
+    /// ```
+    pub const fn short_collides(&self, other: &Self, prefix_bits: u32) -> bool {
+        let diff = self.display_value() ^ other.display_value();
+        match diff.checked_shr(32u32.saturating_sub(prefix_bits)) {
+            Some(v) => v == 0,
+            None => true,
+        }
     }
-}
 
-impl AsRef<VolumeId32> for VolumeId32 {
-    #[inline]
-    fn as_ref(&self) -> &VolumeId32 {
-        self
+    /// Returns the absolute numeric distance between two VolumeId32s.
+    ///
+    /// The distance is computed from the [`as_u32`] values of both serials,
+    /// so it is the same regardless of which one is larger.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let a = VolumeId32::from_u32(10);
+    /// let b = VolumeId32::from_u32(15);
+    ///
+    /// assert_eq!(a.numeric_distance(&b), 5);
+    /// assert_eq!(b.numeric_distance(&a), 5);
+    /// ```
+    ///
+    /// [`as_u32`]: #method.as_u32
+    pub const fn numeric_distance(&self, other: &Self) -> u32 {
+        self.as_u32().abs_diff(other.as_u32())
     }
-}
 
-impl AsRef<[u8]> for VolumeId32 {
-    #[inline]
-    fn as_ref(&self) -> &[u8] {
-        &self.0
+    /// Tests if all four bytes of the VolumeId32 are printable ASCII.
+    ///
+    /// Serials are normally random, but occasionally one is set from a string
+    /// and its bytes spell out readable text. Printable ASCII covers the space
+    /// character through `~`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("46415431").unwrap();
+    ///
+    /// assert!(volumeid32.looks_ascii());
+    /// assert!(!VolumeId32::nil().looks_ascii());
+    /// ```
+    pub const fn looks_ascii(&self) -> bool {
+        let mut i = 0;
+        while i < self.0.len() {
+            if !matches!(self.0[i], b' '..=b'~') {
+                return false;
+            }
+            i += 1;
+        }
+
+        return true;
     }
-}
 
-impl TryFrom<&[u8]> for VolumeId32 {
-    type Error = Error;
+    /// Returns the bytes of the VolumeId32 as a string slice if they are all
+    /// printable ASCII, see [`looks_ascii`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("46415431").unwrap();
+    ///
+    /// assert_eq!(volumeid32.as_ascii_str(), Some("FAT1"));
+    /// assert_eq!(VolumeId32::max().as_ascii_str(), None);
+    /// ```
+    ///
+    /// [`looks_ascii`]: #method.looks_ascii
+    pub const fn as_ascii_str(&self) -> Option<&str> {
+        if !self.looks_ascii() {
+            return None;
+        }
 
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        VolumeId32::from_slice(value)
+        match crate::std::str::from_utf8(&self.0) {
+            Ok(s) => Some(s),
+            Err(_) => None,
+        }
     }
-}
 
-#[cfg(feature = "alloc")]
-impl From<VolumeId32> for crate::alloc::vec::Vec<u8> {
-    fn from(value: VolumeId32) -> Self {
-        value.0.to_vec()
+    /// Returns the VolumeId32 with its bytes rotated left by `n` places.
+    ///
+    /// The byte at index `n` becomes the first byte, wrapping around so that
+    /// rotating by a multiple of 4 returns the same VolumeId32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.rotate_bytes(1).to_string(), "a2a3a4a1");
+    /// assert_eq!(volumeid32.rotate_bytes(4), volumeid32);
+    /// ```
+    pub const fn rotate_bytes(self, n: usize) -> Self {
+        let n = n % 4;
+        let mut bytes = [0u8; 4];
+        let mut i = 0;
+
+        while i < 4 {
+            bytes[i] = self.0[(i + n) % 4];
+            i += 1;
+        }
+
+        return VolumeId32(bytes);
     }
-}
 
-#[cfg(feature = "alloc")]
-impl TryFrom<crate::alloc::vec::Vec<u8>> for VolumeId32 {
-    type Error = Error;
+    /// Derives a VolumeId32 from a string label.
+    ///
+    /// The label is hashed with 64-bit FNV-1a and truncated to its low 32 bits,
+    /// so the same label always produces the same VolumeId32, across runs
+    /// and platforms. This is not a cryptographic hash and different labels
+    /// are not guaranteed to produce different serials.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let a = VolumeId32::from_label("backup-disk");
+    /// let b = VolumeId32::from_label("backup-disk");
+    ///
+    /// assert_eq!(a, b);
+    /// assert_ne!(a, VolumeId32::from_label("boot-disk"));
+    /// ```
+    pub const fn from_label(label: &str) -> Self {
+        VolumeId32::from_content_hash(label.as_bytes())
+    }
 
-    fn try_from(value: crate::alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
-        VolumeId32::from_slice(&value)
+    /// Derives a VolumeId32 from arbitrary bytes, such as the contents of a
+    /// disk image.
+    ///
+    /// `data` is hashed with 64-bit FNV-1a and truncated to its low 32 bits,
+    /// the same as [`from_label`], so identical content always produces the
+    /// same VolumeId32, across runs and platforms. This is not a cryptographic
+    /// hash: it is easy to find different content with the same serial, so it
+    /// must not be used to verify content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let a = VolumeId32::from_content_hash(&[0xeb, 0x58, 0x90]);
+    /// let b = VolumeId32::from_content_hash(&[0xeb, 0x58, 0x90]);
+    ///
+    /// assert_eq!(a, b);
+    /// assert_eq!(
+    ///     VolumeId32::from_content_hash(b"boot-disk"),
+    ///     VolumeId32::from_label("boot-disk"),
+    /// );
+    /// ```
+    ///
+    /// [`from_label`]: #method.from_label
+    pub const fn from_content_hash(data: &[u8]) -> Self {
+        VolumeId32::from_u32(crate::common::fnv1a_64(data) as u32)
     }
-}
 
-impl Default for VolumeId32 {
-    fn default() -> Self {
-        Self::nil()
+    /// Returns the lesser of two VolumeId32s, comparing their bytes
+    /// lexicographically.
+    ///
+    /// This gives the same result as [`Ord::min`], but can be used in `const`
+    /// contexts. If both are equal, `self` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// const A: VolumeId32 = VolumeId32::nil();
+    /// const B: VolumeId32 = VolumeId32::max();
+    /// const MIN: VolumeId32 = B.min_bytes(A);
+    ///
+    /// assert_eq!(MIN, A);
+    /// ```
+    pub const fn min_bytes(self, other: Self) -> Self {
+        if self.bytes_gt(&other) { other } else { self }
+    }
+
+    /// Returns the greater of two VolumeId32s, comparing their bytes
+    /// lexicographically.
+    ///
+    /// This gives the same result as [`Ord::max`], but can be used in `const`
+    /// contexts. If both are equal, `other` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// const A: VolumeId32 = VolumeId32::nil();
+    /// const B: VolumeId32 = VolumeId32::max();
+    /// const MAX: VolumeId32 = A.max_bytes(B);
+    ///
+    /// assert_eq!(MAX, B);
+    /// ```
+    pub const fn max_bytes(self, other: Self) -> Self {
+        if self.bytes_gt(&other) { self } else { other }
+    }
+
+    const fn bytes_gt(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < 4 {
+            if self.0[i] != other.0[i] {
+                return self.0[i] > other.0[i];
+            }
+            i += 1;
+        }
+
+        return false;
+    }
+
+    /// Derives a child VolumeId32 by XORing each byte with `salt`.
+    ///
+    /// The derivation is reversible: deriving the child again with the same
+    /// salt gives back the original VolumeId32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let parent = VolumeId32::parse("6ddcf6da").unwrap();
+    /// let salt = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// let child = parent.derive(salt);
+    ///
+    /// assert_ne!(child, parent);
+    /// assert_eq!(child.derive(salt), parent);
+    /// ```
+    pub const fn derive(&self, salt: VolumeId32) -> VolumeId32 {
+        let mut bytes = self.0;
+        let mut i = 0;
+
+        while i < 4 {
+            bytes[i] ^= salt.0[i];
+            i += 1;
+        }
+
+        return VolumeId32(bytes);
+    }
+
+    /// Get the byte at index `i` of the VolumeId32, in the order it is stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than 4. See [`get_byte`] for a
+    /// non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.byte(1), 0xa2);
+    /// ```
+    ///
+    /// [`get_byte`]: #method.get_byte
+    pub const fn byte(&self, i: usize) -> u8 {
+        self.0[i]
+    }
+
+    /// Get the byte at index `i` of the VolumeId32, or `None` if `i` is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.get_byte(0), Some(0xa1));
+    /// assert_eq!(volumeid32.get_byte(4), None);
+    /// ```
+    pub const fn get_byte(&self, i: usize) -> Option<u8> {
+        if i < 4 { Some(self.0[i]) } else { None }
+    }
+
+    /// Anonymizes the VolumeId32 with `key`, giving a VolumeId32 that can be
+    /// shared in place of the original, for example in published disk images.
+    ///
+    /// The VolumeId32 is XORed with a mask derived from `key`, so the result
+    /// is deterministic and [`deanonymize`] with the same key reverses it.
+    ///
+    /// This is obfuscation, not encryption: anyone who learns one original
+    /// and anonymized pair for a key can reverse every other serial anonymized
+    /// with that key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    ///
+    /// let anonymized = volumeid32.anonymize(42);
+    ///
+    /// assert_ne!(anonymized, volumeid32);
+    /// assert_eq!(anonymized.deanonymize(42), volumeid32);
+    /// ```
+    ///
+    /// [`deanonymize`]: #method.deanonymize
+    pub const fn anonymize(&self, key: u32) -> VolumeId32 {
+        self.derive(VolumeId32::anonymize_mask(key))
+    }
+
+    /// Reverses [`anonymize`] with the same `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    ///
+    /// assert_eq!(volumeid32.anonymize(7).deanonymize(7), volumeid32);
+    /// ```
+    ///
+    /// [`anonymize`]: #method.anonymize
+    pub const fn deanonymize(&self, key: u32) -> VolumeId32 {
+        self.derive(VolumeId32::anonymize_mask(key))
+    }
+
+    const fn anonymize_mask(key: u32) -> VolumeId32 {
+        VolumeId32::from_u32(crate::common::fnv1a_64(&key.to_le_bytes()) as u32)
+    }
+
+    /// Reads the serial number from a FAT32 boot sector.
+    ///
+    /// The serial is stored little-endian at offset `0x43`. Since the
+    /// sector is a fixed-size array this can't fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let mut bs = [0u8; 512];
+    /// bs[0x43..0x43+4].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+    ///
+    /// let volumeid32 = VolumeId32::from_fat32_sector(&bs);
+    ///
+    /// assert_eq!(volumeid32.as_u32(), 0x6ddcf6da);
+    /// ```
+    pub const fn from_fat32_sector(bs: &[u8; 512]) -> Self {
+        VolumeId32::from_sector_at(bs, crate::fs::FAT32_SERIAL_OFFSET)
+    }
+
+    /// Reads the serial number from a FAT12/16 boot sector.
+    ///
+    /// The serial is stored little-endian at offset `0x27`. Since the
+    /// sector is a fixed-size array this can't fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let mut bs = [0u8; 512];
+    /// bs[0x27..0x27+4].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+    ///
+    /// let volumeid32 = VolumeId32::from_fat16_sector(&bs);
+    ///
+    /// assert_eq!(volumeid32.as_u32(), 0x6ddcf6da);
+    /// ```
+    pub const fn from_fat16_sector(bs: &[u8; 512]) -> Self {
+        VolumeId32::from_sector_at(bs, crate::fs::FAT16_SERIAL_OFFSET)
+    }
+
+    /// Reads the serial number from an exFAT boot sector.
+    ///
+    /// The serial is stored little-endian at offset `0x64`. Since the
+    /// sector is a fixed-size array this can't fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let mut bs = [0u8; 512];
+    /// bs[0x64..0x64+4].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+    ///
+    /// let volumeid32 = VolumeId32::from_exfat_sector(&bs);
+    ///
+    /// assert_eq!(volumeid32.as_u32(), 0x6ddcf6da);
+    /// ```
+    pub const fn from_exfat_sector(bs: &[u8; 512]) -> Self {
+        VolumeId32::from_sector_at(bs, crate::fs::EXFAT_SERIAL_OFFSET)
+    }
+
+    const fn from_sector_at(bs: &[u8; 512], offset: usize) -> Self {
+        VolumeId32([bs[offset], bs[offset + 1], bs[offset + 2], bs[offset + 3]])
+    }
+
+    /// Tests if `field` holds this VolumeId32 in either byte order.
+    ///
+    /// This is a lenient check for tools that aren't sure how an image stored
+    /// its serial number. It accepts the bytes as stored and their reverse, so
+    /// it can match serials that a strict comparison would reject.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    ///
+    /// assert!(volumeid32.matches_disk_field([0x6d, 0xdc, 0xf6, 0xda]));
+    /// assert!(volumeid32.matches_disk_field([0xda, 0xf6, 0xdc, 0x6d]));
+    /// ```
+    pub const fn matches_disk_field(&self, field: [u8; 4]) -> bool {
+        self.as_u32() == u32::from_le_bytes(field) || self.as_u32() == u32::from_be_bytes(field)
+    }
+
+    /// Returns which of `n` buckets the VolumeId32 falls into, computed as
+    /// [`as_u32`] modulo `n`.
+    ///
+    /// Using [`as_u32`] means the bucket is based on the on-disk byte order
+    /// on every target. If `n` is 0 there are no buckets and 0 is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_u32(10);
+    ///
+    /// assert_eq!(volumeid32.bucket(4), 2);
+    /// assert_eq!(volumeid32.bucket(0), 0);
+    /// ```
+    ///
+    /// [`as_u32`]: #method.as_u32
+    pub const fn bucket(&self, n: u32) -> u32 {
+        match self.as_u32().checked_rem(n) {
+            Some(bucket) => bucket,
+            None => 0,
+        }
+    }
+
+    /// Guesses whether the VolumeId32 was read with its bytes in the wrong
+    /// order.
+    ///
+    /// A value like `a2a10000` has zero bytes at the end of its displayed form
+    /// and reads as `0000a1a2` the other way around. Serials are normally
+    /// random, so zero padding at the end hints at a byte order bug, while
+    /// padding at the start is what a small number looks like. This returns
+    /// `true` if the displayed form ends with more zero bytes than it starts
+    /// with.
+    ///
+    /// This is a best-effort heuristic: random serials can end in zero bytes
+    /// by chance, and values without zero bytes give no hint either way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// assert!(VolumeId32::parse("a2a10000").unwrap().likely_byteswapped());
+    /// assert!(!VolumeId32::parse("0000a1a2").unwrap().likely_byteswapped());
+    /// ```
+    pub const fn likely_byteswapped(&self) -> bool {
+        let displayed = self.display_value();
+        if displayed == 0 {
+            return false;
+        }
+
+        displayed.trailing_zeros() / 8 > displayed.leading_zeros() / 8
+    }
+
+    /// Computes the IEEE CRC32 of the 4 stored bytes of the VolumeId32.
+    ///
+    /// This is the CRC32 used by zlib, Ethernet and PNG, with the reflected
+    /// polynomial `0xEDB88320`, an initial value of `0xFFFFFFFF` and a final
+    /// XOR of `0xFFFFFFFF`. It's useful for writing checksummed records
+    /// without another dependency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// assert_eq!(VolumeId32::nil().crc32(), 0x2144df1c);
+    /// ```
+    pub const fn crc32(&self) -> u32 {
+        crate::common::crc32(&self.0)
+    }
+
+    /// Writes the 4 stored bytes of the VolumeId32 into the start of `dst`.
+    ///
+    /// Any bytes of `dst` past the first 4 are left untouched. See
+    /// [`copy_into_le`] to write the bytes in reverse order.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `dst` is shorter than 4 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    /// let mut dst = [0u8; 6];
+    ///
+    /// volumeid32.copy_into(&mut dst).expect("Destination should be large enough");
+    ///
+    /// assert_eq!(dst, [0xa1, 0xa2, 0xa3, 0xa4, 0, 0]);
+    /// ```
+    ///
+    /// [`copy_into_le`]: #method.copy_into_le
+    pub fn copy_into(&self, dst: &mut [u8]) -> Result<(), Error> {
+        match dst.get_mut(..4) {
+            Some(dst) => {
+                dst.copy_from_slice(&self.0);
+                return Ok(());
+            }
+            None => {
+                return Err(Error(ErrorKind::BufferTooSmall {
+                    len: dst.len(),
+                    needed: 4,
+                }));
+            }
+        }
+    }
+
+    /// Writes the 4 stored bytes of the VolumeId32 into the start of `dst`,
+    /// in reverse order.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `dst` is shorter than 4 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    /// let mut dst = [0u8; 4];
+    ///
+    /// volumeid32.copy_into_le(&mut dst).expect("Destination should be large enough");
+    ///
+    /// assert_eq!(dst, [0xa4, 0xa3, 0xa2, 0xa1]);
+    /// ```
+    pub fn copy_into_le(&self, dst: &mut [u8]) -> Result<(), Error> {
+        self.copy_into(dst)?;
+        dst[..4].reverse();
+
+        return Ok(());
+    }
+
+    /// Compares two VolumeId32s by the value Windows displays, which is the
+    /// [`as_u32`] value shown as `HHHH-HHHH`.
+    ///
+    /// The derived [`Ord`] compares the stored bytes, so a list sorted with it
+    /// looks scrambled next to the output of `vol`. Use this to sort the way
+    /// `vol` shows them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// # use std::cmp::Ordering;
+    /// let a = VolumeId32::from_u32(0x0000_00ff);
+    /// let b = VolumeId32::from_u32(0x0100_0000);
+    ///
+    /// assert_eq!(a.cmp_windows(&b), Ordering::Less);
+    /// assert_eq!(a.cmp(&b), Ordering::Greater);
+    /// ```
+    ///
+    /// [`as_u32`]: #method.as_u32
+    pub const fn cmp_windows(&self, other: &Self) -> crate::std::cmp::Ordering {
+        let (a, b) = (self.as_u32(), other.as_u32());
+
+        if a < b {
+            crate::std::cmp::Ordering::Less
+        } else if a > b {
+            crate::std::cmp::Ordering::Greater
+        } else {
+            crate::std::cmp::Ordering::Equal
+        }
+    }
+
+    /// Returns the 4 stored bytes of the VolumeId32 as decimal octets.
+    ///
+    /// This is the same as [`into_bytes`], named for code that treats the
+    /// serial as dotted decimal octets. See [`dotted`] to display them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.octets(), [161, 162, 163, 164]);
+    /// ```
+    ///
+    /// [`into_bytes`]: #method.into_bytes
+    /// [`dotted`]: #method.dotted
+    #[inline]
+    pub const fn octets(&self) -> [u8; 4] {
+        self.0
+    }
+
+    /// Creates a VolumeId32 from 4 decimal octets, in the order they are
+    /// stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_octets([161, 162, 163, 164]);
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    #[inline]
+    pub const fn from_octets(octets: [u8; 4]) -> Self {
+        VolumeId32(octets)
+    }
+
+    /// Creates a VolumeId32 from 4 bytes read from disk, in the byte order
+    /// given by `stored`.
+    ///
+    /// This is [`from_bytes`] for [`Endianness::Little`] and
+    /// [`from_bytes_be`] for [`Endianness::Big`], for readers that determine
+    /// the byte order at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::{Endianness, id32::VolumeId32};
+    /// let bytes = [0xa1, 0xa2, 0xa3, 0xa4];
+    ///
+    /// let little = VolumeId32::from_field(bytes, Endianness::Little);
+    /// let big = VolumeId32::from_field(bytes, Endianness::Big);
+    ///
+    /// assert_eq!(little.to_string(), "a1a2a3a4");
+    /// assert_eq!(big.to_string(), "a4a3a2a1");
+    /// ```
+    ///
+    /// [`from_bytes`]: #method.from_bytes
+    /// [`from_bytes_be`]: #method.from_bytes_be
+    /// [`Endianness::Little`]: crate::Endianness::Little
+    /// [`Endianness::Big`]: crate::Endianness::Big
+    pub const fn from_field(bytes: [u8; 4], stored: crate::Endianness) -> Self {
+        match stored {
+            crate::Endianness::Little => VolumeId32::from_bytes(bytes),
+            crate::Endianness::Big => VolumeId32::from_bytes_be(bytes),
+        }
+    }
+
+    /// Combines VolumeId32s into one by XORing them together.
+    ///
+    /// The result doesn't depend on the order of `ids`, so it can be used as a
+    /// fingerprint of a set of volumes. A VolumeId32 that appears twice cancels
+    /// itself out. An empty iterator gives [`nil`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let a = VolumeId32::from_u32(0x6ddcf6da);
+    /// let b = VolumeId32::from_u32(0xa1a2a3a4);
+    ///
+    /// assert_eq!(VolumeId32::xor_all([a, b]), VolumeId32::xor_all([b, a]));
+    /// assert_eq!(VolumeId32::xor_all([a, b, a]), b);
+    /// ```
+    ///
+    /// [`nil`]: #method.nil
+    pub fn xor_all<I: IntoIterator<Item = VolumeId32>>(ids: I) -> VolumeId32 {
+        ids.into_iter()
+            .fold(VolumeId32::nil(), |acc, id| acc.derive(id))
+    }
+
+    /// Combines VolumeId32s into one by adding their [`as_u32`] values,
+    /// wrapping on overflow.
+    ///
+    /// Unlike [`xor_all`], a VolumeId32 that appears twice doesn't cancel
+    /// itself out. An empty iterator gives [`nil`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let a = VolumeId32::from_u32(1);
+    /// let b = VolumeId32::from_u32(u32::MAX);
+    ///
+    /// assert_eq!(VolumeId32::sum_wrapping([a, a]).as_u32(), 2);
+    /// assert_eq!(VolumeId32::sum_wrapping([a, b]), VolumeId32::nil());
+    /// ```
+    ///
+    /// [`as_u32`]: #method.as_u32
+    /// [`xor_all`]: #method.xor_all
+    /// [`nil`]: #method.nil
+    pub fn sum_wrapping<I: IntoIterator<Item = VolumeId32>>(ids: I) -> VolumeId32 {
+        VolumeId32::from_u32(
+            ids.into_iter()
+                .fold(0, |acc: u32, id| acc.wrapping_add(id.as_u32())),
+        )
+    }
+
+    /// Views a borrowed byte array as a VolumeId32, without copying.
+    ///
+    /// VolumeId32 is `#[repr(transparent)]` over `[u8; 4]`, so it has the
+    /// same size, alignment and validity as the array and the reference can be
+    /// cast directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let bytes = [0xa1, 0xa2, 0xa3, 0xa4];
+    ///
+    /// let volumeid32 = VolumeId32::from_bytes_ref(&bytes);
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    #[inline]
+    pub const fn from_bytes_ref(b: &[u8; 4]) -> &VolumeId32 {
+        // SAFETY: VolumeId32 is `#[repr(transparent)]` over `[u8; 4]`
+        unsafe { &*(b as *const [u8; 4] as *const VolumeId32) }
+    }
+
+    /// Tests if all 4 bytes of the VolumeId32 are the same, like `abababab`.
+    ///
+    /// This includes [`nil`] and [`max`]. Such serials are unlikely to be
+    /// random, and usually come from formatting tools that didn't set one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// assert!(VolumeId32::from_bytes([0xab; 4]).is_all_same_byte());
+    /// assert!(!VolumeId32::from_u32(1).is_all_same_byte());
+    /// ```
+    ///
+    /// [`nil`]: #method.nil
+    /// [`max`]: #method.max
+    pub const fn is_all_same_byte(&self) -> bool {
+        let mut i = 1;
+        while i < 4 {
+            if self.0[i] != self.0[0] {
+                return false;
+            }
+            i += 1;
+        }
+
+        return true;
+    }
+
+    /// Returns the indices of the bytes that differ between the VolumeId32
+    /// and `other`, in increasing order.
+    ///
+    /// The indices are positions in the stored bytes, as returned by
+    /// [`as_bytes`]. This doesn't allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let a = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+    /// let b = VolumeId32::from_bytes([0xa1, 0xff, 0xa3, 0x00]);
+    ///
+    /// assert!(a.diff_positions(&b).eq([1, 3]));
+    /// assert_eq!(a.diff_positions(&a).count(), 0);
+    /// ```
+    ///
+    /// [`as_bytes`]: #method.as_bytes
+    pub fn diff_positions(&self, other: &Self) -> impl Iterator<Item = usize> + use<> {
+        let (a, b) = (self.0, other.0);
+        (0..4).filter(move |&i| a[i] != b[i])
+    }
+
+    /// Returns how many different byte values the VolumeId32 contains, from
+    /// 1 to 4.
+    ///
+    /// A low count, like 1 for `aaaaaaaa`, suggests the serial wasn't
+    /// generated randomly. Unlike [`is_all_same_byte`], this also shows
+    /// serials that only repeat some of their bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let repeated = VolumeId32::from_bytes([0xaa; 4]);
+    /// let distinct = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+    ///
+    /// assert_eq!(repeated.distinct_byte_count(), 1);
+    /// assert_eq!(distinct.distinct_byte_count(), 4);
+    /// ```
+    ///
+    /// [`is_all_same_byte`]: #method.is_all_same_byte
+    pub const fn distinct_byte_count(&self) -> u8 {
+        let mut count = 0;
+        let mut i = 0;
+        while i < 4 {
+            let mut j = 0;
+            while j < i && self.0[j] != self.0[i] {
+                j += 1;
+            }
+            if j == i {
+                count += 1;
+            }
+            i += 1;
+        }
+
+        return count;
+    }
+
+    /// Returns the four bytes to write into the serial number field of a FAT
+    /// BIOS parameter block.
+    ///
+    /// Write these four bytes, in this order, at offset `0x43` of a FAT32
+    /// boot sector or `0x27` of a FAT12/16 boot sector. They are the
+    /// little-endian bytes of [`as_u32`], which is also the order the
+    /// VolumeId32 stores them in, so this is the same as [`into_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_u32(0x6ddcf6da);
+    /// let mut bs = [0u8; 512];
+    ///
+    /// bs[0x43..0x47].copy_from_slice(&volumeid32.to_bpb_field());
+    ///
+    /// assert_eq!(VolumeId32::from_fat32_sector(&bs), volumeid32);
+    /// ```
+    ///
+    /// [`as_u32`]: #method.as_u32
+    /// [`into_bytes`]: #method.into_bytes
+    pub const fn to_bpb_field(&self) -> [u8; 4] {
+        self.0
+    }
+
+    /// Returns a copy of the VolumeId32 with some of its hex digits set to
+    /// zero, for partially redacting a serial while keeping its shape.
+    ///
+    /// Bit `i` of `mask` selects the `i`th of the 8 hex digits shown by
+    /// [`Display`], counting from the left and starting at 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.redact_nibbles(0x0f).to_string(), "0000a3a4");
+    /// ```
+    ///
+    /// [`Display`]: core::fmt::Display
+    pub const fn redact_nibbles(&self, mask: u8) -> VolumeId32 {
+        let mut bytes = self.0;
+        let mut i = 0;
+        while i < 8 {
+            if mask & (1 << i) != 0 {
+                bytes[i / 2] &= if i % 2 == 0 { 0x0f } else { 0xf0 };
+            }
+            i += 1;
+        }
+
+        return VolumeId32(bytes);
+    }
+
+    /// Returns the 4 stored bytes of the VolumeId32 followed by a parity
+    /// byte, the XOR of those bytes.
+    ///
+    /// This lets a receiver detect a corrupted byte with
+    /// [`from_parity_checked`]. It can't detect every error, such as the
+    /// same bit flipping in two bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+    ///
+    /// assert_eq!(volumeid32.with_parity(), [0xa1, 0xa2, 0xa3, 0xa4, 0x04]);
+    /// ```
+    ///
+    /// [`from_parity_checked`]: #method.from_parity_checked
+    pub const fn with_parity(&self) -> [u8; 5] {
+        let mut bytes = [0u8; 5];
+        let mut i = 0;
+        while i < 4 {
+            bytes[i] = self.0[i];
+            bytes[4] ^= self.0[i];
+            i += 1;
+        }
+
+        return bytes;
+    }
+
+    /// Creates a VolumeId32 from bytes produced by [`with_parity`], checking
+    /// the parity byte.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the last byte isn't the XOR of
+    /// the other 4.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_u32(0x6ddcf6da);
+    /// let mut bytes = volumeid32.with_parity();
+    ///
+    /// assert_eq!(VolumeId32::from_parity_checked(&bytes), Ok(volumeid32));
+    ///
+    /// bytes[1] ^= 0x10;
+    /// assert!(VolumeId32::from_parity_checked(&bytes).is_err());
+    /// ```
+    ///
+    /// [`with_parity`]: #method.with_parity
+    pub fn from_parity_checked(bytes: &[u8; 5]) -> Result<Self, Error> {
+        let mut serial = [0u8; 4];
+        serial.copy_from_slice(&bytes[..4]);
+
+        let volumeid32 = VolumeId32::from_bytes(serial);
+        let expected = volumeid32.with_parity()[4];
+        if bytes[4] != expected {
+            return Err(Error(ErrorKind::ParseParity {
+                expected,
+                found: bytes[4],
+            }));
+        }
+
+        return Ok(volumeid32);
+    }
+
+    /// Tests if the VolumeId32 matches `pattern`, a string of 8 hex digits
+    /// or `?` wildcards like `a1??a3a4`.
+    ///
+    /// Each character of `pattern` is compared with the digit in the same
+    /// position of the simple format, ignoring case, and `?` matches any
+    /// digit. A pattern that isn't 8 characters long never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert!(volumeid32.matches_pattern("a1??a3a4"));
+    /// assert!(!volumeid32.matches_pattern("b1??????"));
+    /// ```
+    pub fn matches_pattern(&self, pattern: &str) -> bool {
+        let pattern = pattern.as_bytes();
+        if pattern.len() != 8 {
+            return false;
+        }
+
+        self.simple_str()
+            .bytes()
+            .zip(pattern)
+            .all(|(digit, &p)| p == b'?' || p.eq_ignore_ascii_case(&digit))
+    }
+
+    /// Tests if the VolumeId32 equals `other`, either as is or with the
+    /// order of its bytes reversed.
+    ///
+    /// This is for reconciling serials read by tools that may disagree on
+    /// endianness. To compare a VolumeId32 with raw bytes from disk, see
+    /// [`matches_disk_field`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let a = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+    /// let b = VolumeId32::from_bytes([0xa4, 0xa3, 0xa2, 0xa1]);
+    ///
+    /// assert!(a.eq_byteswapped(&b));
+    /// assert!(a.eq_byteswapped(&a));
+    /// assert!(!a.eq_byteswapped(&VolumeId32::nil()));
+    /// ```
+    ///
+    /// [`matches_disk_field`]: #method.matches_disk_field
+    pub const fn eq_byteswapped(&self, other: &Self) -> bool {
+        self.as_u32() == other.as_u32() || self.as_u32() == other.as_u32().swap_bytes()
+    }
+
+    /// Returns a color derived from the VolumeId32, as red, green and blue
+    /// components.
+    ///
+    /// Stored byte `i` is XORed into component `i % 3`, so red is
+    /// `b[0] ^ b[3]`, green is `b[1]` and blue is `b[2]`. Every byte affects
+    /// the color, the same VolumeId32 always gives the same color, and
+    /// [`nil`] is black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_bytes([0x10, 0x20, 0x30, 0x01]);
+    ///
+    /// assert_eq!(volumeid32.to_rgb(), (0x11, 0x20, 0x30));
+    /// assert_eq!(VolumeId32::nil().to_rgb(), (0, 0, 0));
+    /// ```
+    ///
+    /// [`nil`]: #method.nil
+    pub const fn to_rgb(&self) -> (u8, u8, u8) {
+        let mut rgb = [0u8; 3];
+        let mut i = 0;
+        while i < 4 {
+            rgb[i % 3] ^= self.0[i];
+            i += 1;
+        }
+
+        return (rgb[0], rgb[1], rgb[2]);
+    }
+
+    /// Returns the [`as_u32`] value of the VolumeId32 reinterpreted as a
+    /// signed integer, for storage that only has signed 32-bit columns.
+    ///
+    /// The bits are unchanged, so serials with the high bit set become
+    /// negative. Use [`from_i32`] to get the VolumeId32 back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_u32(0xa1a2a3a4);
+    ///
+    /// assert_eq!(volumeid32.as_i32(), -1583176796);
+    /// assert_eq!(VolumeId32::from_i32(-1583176796), volumeid32);
+    /// ```
+    ///
+    /// [`as_u32`]: #method.as_u32
+    /// [`from_i32`]: #method.from_i32
+    pub const fn as_i32(&self) -> i32 {
+        self.as_u32() as i32
+    }
+
+    /// Creates a VolumeId32 from a signed integer produced by [`as_i32`].
+    ///
+    /// The bits of `v` are reinterpreted as the [`as_u32`] value, so
+    /// this is the same as `from_u32(v as u32)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// assert_eq!(VolumeId32::from_i32(-1), VolumeId32::max());
+    /// ```
+    ///
+    /// [`as_i32`]: #method.as_i32
+    /// [`as_u32`]: #method.as_u32
+    pub const fn from_i32(v: i32) -> Self {
+        VolumeId32::from_u32(v as u32)
+    }
+
+    /// Tests if the [`as_u32`] value of the VolumeId32 is between those of
+    /// `low` and `high`, inclusive.
+    ///
+    /// This compares the same way as [`cmp_windows`], not the derived [`Ord`].
+    /// If `low` is greater than `high` the range is empty, so this returns
+    /// `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let low = VolumeId32::from_u32(0x100);
+    /// let high = VolumeId32::from_u32(0x1ff);
+    ///
+    /// assert!(VolumeId32::from_u32(0x180).in_range(low, high));
+    /// assert!(!VolumeId32::from_u32(0x200).in_range(low, high));
+    /// assert!(!VolumeId32::from_u32(0x180).in_range(high, low));
+    /// ```
+    ///
+    /// [`as_u32`]: #method.as_u32
+    /// [`cmp_windows`]: #method.cmp_windows
+    pub const fn in_range(&self, low: VolumeId32, high: VolumeId32) -> bool {
+        let value = self.as_u32();
+        return low.as_u32() <= value && value <= high.as_u32();
+    }
+}
+
+impl crate::std::hash::Hash for VolumeId32 {
+    fn hash<H: crate::std::hash::Hasher>(&self, state: &mut H) {
+        state.write(&self.0);
+    }
+}
+
+impl AsRef<VolumeId32> for VolumeId32 {
+    #[inline]
+    fn as_ref(&self) -> &VolumeId32 {
+        self
+    }
+}
+
+impl AsRef<[u8]> for VolumeId32 {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for VolumeId32 {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        VolumeId32::from_slice(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<VolumeId32> for crate::alloc::vec::Vec<u8> {
+    fn from(value: VolumeId32) -> Self {
+        value.0.to_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<crate::alloc::vec::Vec<u8>> for VolumeId32 {
+    type Error = Error;
+
+    fn try_from(value: crate::alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
+        VolumeId32::from_slice(&value)
+    }
+}
+
+impl Default for VolumeId32 {
+    fn default() -> Self {
+        Self::nil()
+    }
+}
+
+impl crate::VolumeIdentifier for VolumeId32 {
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        VolumeId32::parse(input)
+    }
+
+    fn nil() -> Self {
+        VolumeId32::nil()
+    }
+
+    fn max() -> Self {
+        VolumeId32::max()
+    }
+
+    fn is_nil(&self) -> bool {
+        VolumeId32::is_nil(self)
+    }
+
+    fn is_max(&self) -> bool {
+        VolumeId32::is_max(self)
+    }
+}
+
+/// Returns how many of `ids` are degenerate: [`nil`], [`max`] or otherwise
+/// made of a single repeated byte.
+///
+/// This is useful for finding volumes in bulk whose serial was never set
+/// properly. See [`is_all_same_byte`].
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::id32::{VolumeId32, count_degenerate};
+/// let ids = [VolumeId32::nil(), VolumeId32::from_u32(0x1234), VolumeId32::max()];
+///
+/// assert_eq!(count_degenerate(&ids), 2);
+/// ```
+///
+/// [`nil`]: struct.VolumeId32.html#method.nil
+/// [`max`]: struct.VolumeId32.html#method.max
+/// [`is_all_same_byte`]: struct.VolumeId32.html#method.is_all_same_byte
+pub fn count_degenerate(ids: &[VolumeId32]) -> usize {
+    ids.iter().filter(|id| id.is_all_same_byte()).count()
+}
+
+#[cfg(feature = "alloc")]
+impl VolumeId32 {
+    /// Sorts `ids` the way `vol` shows them, using [`cmp_windows`].
+    ///
+    /// The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let mut ids = [VolumeId32::from_u32(0x0100_0000), VolumeId32::from_u32(0x0000_00ff)];
+    ///
+    /// VolumeId32::sort_windows(&mut ids);
+    ///
+    /// assert_eq!(ids[0].as_u32(), 0x0000_00ff);
+    /// ```
+    ///
+    /// [`cmp_windows`]: #method.cmp_windows
+    pub fn sort_windows(ids: &mut [VolumeId32]) {
+        ids.sort_by(VolumeId32::cmp_windows);
+    }
+}
+
+#[cfg(feature = "std")]
+impl VolumeId32 {
+    /// Reads a VolumeId32 from the next 4 bytes of `r`, in the order they
+    /// are stored.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if reading fails. If `r` ends
+    /// before 4 bytes are read, the error has the kind
+    /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// # use std::io::Cursor;
+    /// let mut cursor = Cursor::new([0xa1, 0xa2, 0xa3, 0xa4]);
+    ///
+    /// let volumeid32 = VolumeId32::from_reader(&mut cursor)
+    ///     .expect("Failed Reading Bytes");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    pub fn from_reader<R: std::io::Read>(r: &mut R) -> std::io::Result<Self> {
+        let mut bytes = [0u8; 4];
+        r.read_exact(&mut bytes)?;
+
+        return Ok(VolumeId32::from_bytes(bytes));
+    }
+
+    /// Writes the 4 bytes of the VolumeId32 to `w` in the byte order `order`.
+    ///
+    /// [`Endianness::Little`] writes the bytes in the order they are stored
+    /// and [`Endianness::Big`] writes them reversed, the inverse of
+    /// [`from_field`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::{Endianness, id32::VolumeId32};
+    /// let volumeid32 = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+    /// let mut buf = Vec::new();
+    ///
+    /// volumeid32
+    ///     .to_writer(&mut buf, Endianness::Big)
+    ///     .expect("Failed Writing Bytes");
+    ///
+    /// assert_eq!(buf, [0xa4, 0xa3, 0xa2, 0xa1]);
+    /// ```
+    ///
+    /// [`Endianness::Little`]: crate::Endianness::Little
+    /// [`Endianness::Big`]: crate::Endianness::Big
+    /// [`from_field`]: #method.from_field
+    pub fn to_writer<W: std::io::Write>(
+        &self,
+        w: &mut W,
+        order: crate::Endianness,
+    ) -> std::io::Result<()> {
+        match order {
+            crate::Endianness::Little => w.write_all(&self.0),
+            crate::Endianness::Big => w.write_all(&self.to_bytes_be()),
+        }
+    }
+
+    /// Reads the serial number from a boot sector in `r`, at the offset used
+    /// by `fs`.
+    ///
+    /// The boot sector must start at position 0 of `r`. After reading, `r` is
+    /// left just past the serial number.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if seeking or reading fails, for
+    /// example if `r` ends before the serial number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::{fs::FatKind, id32::VolumeId32};
+    /// # use std::io::Cursor;
+    /// let mut bs = [0u8; 512];
+    /// bs[0x43..0x47].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d]);
+    ///
+    /// let volumeid32 = VolumeId32::read_at(&mut Cursor::new(bs), FatKind::Fat32)
+    ///     .expect("Failed Reading Boot Sector");
+    ///
+    /// assert_eq!(volumeid32.as_u32(), 0x6ddcf6da);
+    /// ```
+    pub fn read_at<R: std::io::Read + std::io::Seek>(
+        r: &mut R,
+        fs: crate::fs::FatKind,
+    ) -> std::io::Result<Self> {
+        let mut bytes = [0u8; 4];
+
+        r.seek(std::io::SeekFrom::Start(fs.serial_offset() as u64))?;
+        r.read_exact(&mut bytes)?;
+
+        return Ok(VolumeId32::from_bytes(bytes));
+    }
+
+    /// Writes the serial number into a boot sector in `w`, at the offset used
+    /// by `fs`.
+    ///
+    /// The boot sector must start at position 0 of `w`. After writing, `w` is
+    /// left just past the serial number.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if seeking or writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::{fs::FatKind, id32::VolumeId32};
+    /// # use std::io::Cursor;
+    /// let mut cursor = Cursor::new([0u8; 512]);
+    ///
+    /// VolumeId32::from_u32(0x6ddcf6da)
+    ///     .write_at(&mut cursor, FatKind::ExFat)
+    ///     .expect("Failed Writing Boot Sector");
+    ///
+    /// assert_eq!(&cursor.get_ref()[0x64..0x68], &[0xda, 0xf6, 0xdc, 0x6d]);
+    /// ```
+    pub fn write_at<W: std::io::Write + std::io::Seek>(
+        &self,
+        w: &mut W,
+        fs: crate::fs::FatKind,
+    ) -> std::io::Result<()> {
+        w.seek(std::io::SeekFrom::Start(fs.serial_offset() as u64))?;
+        w.write_all(&self.0)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl VolumeId32 {
+    /// The number of values [`new_random_distinct`] tries before giving up.
+    ///
+    /// [`new_random_distinct`]: #method.new_random_distinct
+    pub const RANDOM_DISTINCT_ATTEMPTS: usize = 64;
+
+    /// Generates a random VolumeId32 that isn't in `existing`.
+    ///
+    /// Up to [`RANDOM_DISTINCT_ATTEMPTS`] values are drawn from `rng`, and the
+    /// first one not found in `existing` is returned. Returns `None` if every
+    /// attempt collided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// # use rand_core::SeedableRng;
+    /// let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(1);
+    /// let existing = [VolumeId32::nil(), VolumeId32::max()];
+    ///
+    /// let volumeid32 = VolumeId32::new_random_distinct(&existing, &mut rng)
+    ///     .expect("Failed to generate a distinct VolumeId32");
+    ///
+    /// assert!(!existing.contains(&volumeid32));
+    /// ```
+    ///
+    /// [`RANDOM_DISTINCT_ATTEMPTS`]: #associatedconstant.RANDOM_DISTINCT_ATTEMPTS
+    pub fn new_random_distinct(
+        existing: &[VolumeId32],
+        rng: &mut impl rand_core::RngCore,
+    ) -> Option<Self> {
+        for _ in 0..Self::RANDOM_DISTINCT_ATTEMPTS {
+            let volumeid32 = VolumeId32::from_u32(rng.next_u32());
+
+            if !existing.contains(&volumeid32) {
+                return Some(volumeid32);
+            }
+        }
+
+        return None;
+    }
+}
+
+/// Returns the smallest number of leading hex digits needed to tell every
+/// [`VolumeId32`] in `ids` apart.
+///
+/// Digits are counted from the start of the displayed (simple) form, so the
+/// result can be used to print abbreviated serials in the same way git
+/// abbreviates commit hashes. If `ids` contains duplicates no prefix can tell
+/// them apart and the full width of 8 digits is returned. An empty slice or a
+/// slice with a single element needs no digits at all.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::id32::{VolumeId32, min_unique_prefix_nibbles};
+/// let ids = [
+///     VolumeId32::parse("6ddcf6da").unwrap(),
+///     VolumeId32::parse("6d12ab34").unwrap(),
+///     VolumeId32::parse("a1a2a3a4").unwrap(),
+/// ];
+///
+/// assert_eq!(min_unique_prefix_nibbles(&ids), 3);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn min_unique_prefix_nibbles(ids: &[VolumeId32]) -> usize {
+    let mut sorted = ids.to_vec();
+    sorted.sort_unstable();
+
+    let mut nibbles = 0;
+    for pair in sorted.windows(2) {
+        let diff = pair[0].display_value() ^ pair[1].display_value();
+        if diff == 0 {
+            return 8;
+        }
+
+        nibbles = nibbles.max(diff.leading_zeros() as usize / 4 + 1);
+    }
+
+    return nibbles;
+}
+
+/// Parses every string in `inputs`, returning the VolumeId32s with duplicates
+/// removed.
+///
+/// Each VolumeId32 is kept at the position it was first seen, so inputs that
+/// differ only in case or format, like `6ddcf6da` and `6DDC-F6DA`, are treated
+/// as duplicates. If an input can't be parsed, the index of the first bad
+/// input is returned along with its error.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::id32::{VolumeId32, canonicalize_unique};
+/// let ids = canonicalize_unique(&["6ddcf6da", "a1a2a3a4", "6DDC-F6DA"]).unwrap();
+///
+/// assert_eq!(
+///     ids,
+///     [
+///         VolumeId32::parse("6ddcf6da").unwrap(),
+///         VolumeId32::parse("a1a2a3a4").unwrap(),
+///     ],
+/// );
+/// assert_eq!(canonicalize_unique(&["6ddcf6da", "xyz"]).unwrap_err().0, 1);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn canonicalize_unique(
+    inputs: &[&str],
+) -> Result<crate::alloc::vec::Vec<VolumeId32>, (usize, Error)> {
+    let mut seen = crate::alloc::collections::BTreeSet::new();
+    let mut ids = crate::alloc::vec::Vec::with_capacity(inputs.len());
+
+    for (index, input) in inputs.iter().enumerate() {
+        let id = VolumeId32::parse(input).map_err(|err| (index, err))?;
+        if seen.insert(id) {
+            ids.push(id);
+        }
+    }
+
+    return Ok(ids);
+}
+
+/// Parses a [`VolumeId32`] from a string literal at compile time.
+///
+/// This is friendlier than unwrapping [`VolumeId32::try_parse`] in a `const`,
+/// and an invalid literal fails the build instead of panicking at runtime.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::{const_volume_id32, id32::VolumeId32};
+/// const VOLUME_ID: VolumeId32 = const_volume_id32!("a1a2a3a4");
+///
+/// assert_eq!(VOLUME_ID.to_string(), "a1a2a3a4");
+/// ```
+///
+/// An invalid literal fails to compile:
+///
+/// ```compile_fail
+/// # use fat_volume_id::{const_volume_id32, id32::VolumeId32};
+/// const VOLUME_ID: VolumeId32 = const_volume_id32!("a1a2a3ag");
+/// ```
+#[macro_export]
+macro_rules! const_volume_id32 {
+    ($s:expr) => {{
+        const VOLUME_ID32: $crate::id32::VolumeId32 = match $crate::id32::VolumeId32::try_parse($s)
+        {
+            Ok(volumeid32) => volumeid32,
+            Err(_) => panic!("invalid VolumeId32 literal"),
+        };
+        VOLUME_ID32
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_collides() {
+        let a = VolumeId32::parse("6ddc-f6da").unwrap();
+        let b = VolumeId32::parse("6ddf-0000").unwrap();
+
+        assert!(a.short_collides(&b, 0));
+        assert!(a.short_collides(&b, 8));
+        assert!(a.short_collides(&b, 12));
+        assert!(!a.short_collides(&b, 16));
+        assert!(!a.short_collides(&b, 32));
+        assert!(a.short_collides(&a, 32));
+        assert!(a.short_collides(&a, 40));
+    }
+
+    #[test]
+    fn test_numeric_distance() {
+        let a = VolumeId32::from_u32(10);
+        let b = VolumeId32::from_u32(15);
+
+        assert_eq!(a.numeric_distance(&a), 0);
+        assert_eq!(a.numeric_distance(&b), 5);
+        assert_eq!(b.numeric_distance(&a), 5);
+        assert_eq!(
+            VolumeId32::nil().numeric_distance(&VolumeId32::max()),
+            u32::MAX
+        );
+        assert_eq!(
+            VolumeId32::max().numeric_distance(&VolumeId32::nil()),
+            u32::MAX
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_min_unique_prefix_nibbles() {
+        let parse = |s| VolumeId32::parse(s).unwrap();
+
+        assert_eq!(min_unique_prefix_nibbles(&[]), 0);
+        assert_eq!(min_unique_prefix_nibbles(&[parse("6ddcf6da")]), 0);
+
+        let one = [parse("1ddcf6da"), parse("6ddcf6da"), parse("addcf6da")];
+        assert_eq!(min_unique_prefix_nibbles(&one), 1);
+
+        let two = [parse("6ddcf6da"), parse("6adcf6da"), parse("a1a2a3a4")];
+        assert_eq!(min_unique_prefix_nibbles(&two), 2);
+
+        let full = [parse("6ddcf6da"), parse("6ddcf6db")];
+        assert_eq!(min_unique_prefix_nibbles(&full), 8);
+
+        let duplicate = [parse("6ddcf6da"), parse("a1a2a3a4"), parse("6ddc-f6da")];
+        assert_eq!(min_unique_prefix_nibbles(&duplicate), 8);
+    }
+
+    #[test]
+    fn test_looks_ascii() {
+        let ascii = VolumeId32::from_bytes(*b"MSWI");
+        assert!(ascii.looks_ascii());
+        assert_eq!(ascii.as_ascii_str(), Some("MSWI"));
+
+        let binary = VolumeId32::parse("6ddcf6da").unwrap();
+        assert!(!binary.looks_ascii());
+        assert_eq!(binary.as_ascii_str(), None);
+
+        let control = VolumeId32::parse("41420a43").unwrap();
+        assert!(!control.looks_ascii());
+        assert_eq!(control.as_ascii_str(), None);
+    }
+
+    #[test]
+    fn test_rotate_bytes() {
+        const V: VolumeId32 = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+        const ROTATED: VolumeId32 = V.rotate_bytes(3);
+
+        assert_eq!(V.rotate_bytes(0), V);
+        assert_eq!(V.rotate_bytes(4), V);
+        assert_eq!(V.rotate_bytes(1).into_bytes(), [0xa2, 0xa3, 0xa4, 0xa1]);
+        assert_eq!(V.rotate_bytes(2).into_bytes(), [0xa3, 0xa4, 0xa1, 0xa2]);
+        assert_eq!(ROTATED.into_bytes(), [0xa4, 0xa1, 0xa2, 0xa3]);
+        assert_eq!(V.rotate_bytes(5), V.rotate_bytes(1));
+        assert_eq!(V.rotate_bytes(usize::MAX), V.rotate_bytes(3));
+    }
+
+    #[test]
+    fn test_from_label() {
+        assert_eq!(
+            VolumeId32::from_label("a"),
+            VolumeId32::from_u32(0x8601ec8c)
+        );
+        assert_eq!(VolumeId32::from_label(""), VolumeId32::from_u32(0x84222325));
+
+        assert_eq!(
+            VolumeId32::from_label("backup-disk"),
+            VolumeId32::from_label("backup-disk")
+        );
+        assert_ne!(
+            VolumeId32::from_label("backup-disk"),
+            VolumeId32::from_label("backup-disk2")
+        );
+    }
+
+    #[test]
+    fn test_min_max_bytes() {
+        const A: VolumeId32 = match VolumeId32::try_parse("a1a2a3a4") {
+            Ok(v) => v,
+            Err(_) => panic!(),
+        };
+        const B: VolumeId32 = match VolumeId32::try_parse("a1b2a3a4") {
+            Ok(v) => v,
+            Err(_) => panic!(),
+        };
+        const MIN: VolumeId32 = B.min_bytes(A);
+        const MAX: VolumeId32 = A.max_bytes(B);
+
+        assert_eq!(MIN, A);
+        assert_eq!(MAX, B);
+        assert_eq!(A.min_bytes(B), A.min(B));
+        assert_eq!(B.max_bytes(A), B.max(A));
+        assert_eq!(A.min_bytes(A), A);
+        assert_eq!(A.max_bytes(A), A);
+    }
+
+    #[test]
+    fn test_derive() {
+        let parent = VolumeId32::parse("6ddcf6da").unwrap();
+        let salt = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(parent.derive(salt).derive(salt), parent);
+        assert_eq!(parent.derive(VolumeId32::nil()), parent);
+        assert_eq!(parent.derive(parent), VolumeId32::nil());
+        assert_eq!(
+            VolumeId32::nil().derive(VolumeId32::max()),
+            VolumeId32::max()
+        );
+    }
+
+    #[test]
+    fn test_byte() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        for i in 0..4 {
+            assert_eq!(v.byte(i), 0xa1 + i as u8);
+            assert_eq!(v.get_byte(i), Some(0xa1 + i as u8));
+        }
+        assert_eq!(v.get_byte(4), None);
+        assert_eq!(v.get_byte(usize::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_byte_out_of_range() {
+        VolumeId32::nil().byte(4);
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+
+        for key in [0, 1, 42, u32::MAX] {
+            let anonymized = v.anonymize(key);
+
+            assert_ne!(anonymized, v);
+            assert_eq!(anonymized, v.anonymize(key));
+            assert_eq!(anonymized.deanonymize(key), v);
+        }
+        assert_ne!(v.anonymize(1), v.anonymize(2));
+    }
+
+    #[test]
+    fn test_const_integer_constructors() {
+        const V: VolumeId32 = VolumeId32::from_u32(0xa1a2a3a4);
+        const V_BE: VolumeId32 = VolumeId32::from_u32_be(0xa4a3a2a1);
+        const PAIR: VolumeId32 = VolumeId32::from_u16_pair(0xa1a2, 0xa3a4);
+        const PAIR_BE: VolumeId32 = VolumeId32::from_u16_pair_be(0xa4a3, 0xa2a1);
+
+        assert_eq!(V, V_BE);
+        assert_eq!(V, PAIR);
+        assert_eq!(V, PAIR_BE);
+        assert_eq!(V.as_bytes(), &[0xa4, 0xa3, 0xa2, 0xa1]);
+    }
+
+    #[test]
+    fn test_const_accessors() {
+        const V: VolumeId32 = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+        const _: () = assert!(
+            V.as_u32() == 0xa4a3a2a1
+                && V.display_value() == 0xa1a2a3a4
+                && V.byte(0) == 0xa1
+                && V.rotate_bytes(1).display_value() == 0xa2a3a4a1
+                && V.numeric_distance(&VolumeId32::nil()) == 0xa4a3a2a1
+                && V.derive(V).as_u32() == 0
+                && V.min_bytes(VolumeId32::max()).as_u32() == V.as_u32()
+        );
+    }
+
+    #[test]
+    fn test_from_sector() {
+        let serial = [0xda, 0xf6, 0xdc, 0x6d];
+        let expected = VolumeId32::from_u32(0x6ddcf6da);
+
+        let mut bs = [0u8; 512];
+        bs[0x43..0x47].copy_from_slice(&serial);
+        assert_eq!(VolumeId32::from_fat32_sector(&bs), expected);
+        assert_eq!(VolumeId32::from_fat16_sector(&bs), VolumeId32::nil());
+
+        let mut bs = [0u8; 512];
+        bs[0x27..0x2b].copy_from_slice(&serial);
+        assert_eq!(VolumeId32::from_fat16_sector(&bs), expected);
+
+        let mut bs = [0u8; 512];
+        bs[0x64..0x68].copy_from_slice(&serial);
+        assert_eq!(VolumeId32::from_exfat_sector(&bs), expected);
+    }
+
+    #[test]
+    fn test_matches_disk_field() {
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+
+        assert!(v.matches_disk_field([0x6d, 0xdc, 0xf6, 0xda]));
+        assert!(v.matches_disk_field([0xda, 0xf6, 0xdc, 0x6d]));
+        assert!(!v.matches_disk_field([0; 4]));
+        assert!(!v.matches_disk_field(v.rotate_bytes(1).into_bytes()));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_new_random_distinct() {
+        use rand_core::{RngCore, SeedableRng};
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(42);
+        let existing = [
+            VolumeId32::from_u32(rng.next_u32()),
+            VolumeId32::from_u32(rng.next_u32()),
+        ];
+
+        let mut rng = XorShiftRng::seed_from_u64(42);
+        let v = VolumeId32::new_random_distinct(&existing, &mut rng).unwrap();
+        assert!(!existing.contains(&v));
+
+        let mut expected = XorShiftRng::seed_from_u64(42);
+        expected.next_u32();
+        expected.next_u32();
+        assert_eq!(v, VolumeId32::from_u32(expected.next_u32()));
+
+        struct Constant;
+
+        impl RngCore for Constant {
+            fn next_u32(&mut self) -> u32 {
+                0
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                0
+            }
+
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                dst.fill(0);
+            }
+        }
+
+        assert_eq!(
+            VolumeId32::new_random_distinct(&[VolumeId32::nil()], &mut Constant),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bucket() {
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+
+        assert_eq!(v.bucket(1), 0);
+        assert_eq!(v.bucket(16), v.as_bytes()[0] as u32 % 16);
+        assert_eq!(v.bucket(1000), v.as_u32() % 1000);
+        assert_eq!(v.bucket(u32::MAX), v.as_u32() % u32::MAX);
+        assert_eq!(v.bucket(0), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_write_at() {
+        use crate::fs::FatKind;
+        use std::io::Cursor;
+
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+
+        for fs in [FatKind::Fat16, FatKind::Fat32, FatKind::ExFat] {
+            let mut cursor = Cursor::new([0u8; 512]);
+
+            v.write_at(&mut cursor, fs).unwrap();
+            assert_eq!(VolumeId32::read_at(&mut cursor, fs).unwrap(), v);
+
+            let offset = fs.serial_offset();
+            assert_eq!(&cursor.get_ref()[offset..offset + 4], v.as_bytes());
+        }
+
+        assert_eq!(
+            VolumeId32::read_at(&mut Cursor::new([0u8; 0x45]), FatKind::Fat32)
+                .unwrap_err()
+                .kind(),
+            std::io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn test_const_from_slice() {
+        const OK: Result<VolumeId32, Error> =
+            VolumeId32::from_slice_const(&[0xa1, 0xa2, 0xa3, 0xa4]);
+        const SHORT: Result<VolumeId32, Error> = VolumeId32::from_slice_const(&[0xa1, 0xa2, 0xa3]);
+
+        assert_eq!(OK, VolumeId32::from_slice(&[0xa1, 0xa2, 0xa3, 0xa4]));
+        assert_eq!(SHORT, Err(Error(ErrorKind::ParseByteLength { len: 3 })));
+    }
+
+    #[test]
+    fn test_likely_byteswapped() {
+        for swapped in ["a2a10000", "a1a2a300", "a1000000"] {
+            assert!(VolumeId32::parse(swapped).unwrap().likely_byteswapped());
+        }
+
+        for unswapped in ["0000a1a2", "00a1a2a3", "6ddcf6da", "00a1a200", "00000000"] {
+            assert!(!VolumeId32::parse(unswapped).unwrap().likely_byteswapped());
+        }
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(VolumeId32::nil().crc32(), 0x2144df1c);
+        assert_eq!(VolumeId32::from_bytes(*b"1234").crc32(), 0x9be3e0a3);
+        assert_eq!(VolumeId32::max().crc32(), 0xffffffff);
+    }
+
+    #[test]
+    fn test_const_volume_id32() {
+        const VOLUME_ID: VolumeId32 = const_volume_id32!("a1a2a3a4");
+
+        assert_eq!(VOLUME_ID, VolumeId32::parse("a1a2a3a4").unwrap());
+        assert_eq!(const_volume_id32!("A1A2A3A4"), VOLUME_ID);
+    }
+
+    #[test]
+    fn test_copy_into() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        let mut dst = [0xffu8; 5];
+        assert_eq!(v.copy_into(&mut dst), Ok(()));
+        assert_eq!(&dst[..4], v.as_bytes());
+        assert_eq!(dst[4], 0xff);
+
+        assert_eq!(v.copy_into_le(&mut dst), Ok(()));
+        assert_eq!(&dst[..4], &v.to_bytes_be());
+        assert_eq!(dst[4], 0xff);
+
+        let mut short = [0u8; 3];
+        let expected = Err(Error(ErrorKind::BufferTooSmall { len: 3, needed: 4 }));
+        assert_eq!(v.copy_into(&mut short), expected);
+        assert_eq!(v.copy_into_le(&mut short), expected);
+        assert_eq!(short, [0u8; 3]);
+    }
+
+    #[test]
+    fn test_cmp_windows() {
+        use crate::std::cmp::Ordering;
+
+        let a = VolumeId32::from_u32(0x0000_00ff);
+        let b = VolumeId32::from_u32(0x0100_0000);
+
+        assert_eq!(a.cmp_windows(&b), Ordering::Less);
+        assert_eq!(b.cmp_windows(&a), Ordering::Greater);
+        assert_eq!(a.cmp_windows(&a), Ordering::Equal);
+        assert_eq!(a.cmp(&b), Ordering::Greater);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sort_windows() {
+        let mut ids = [
+            VolumeId32::from_u32(0x6ddc_f6da),
+            VolumeId32::from_u32(0x0100_0000),
+            VolumeId32::from_u32(0x0000_00ff),
+        ];
+
+        VolumeId32::sort_windows(&mut ids);
+        assert_eq!(
+            ids.map(|id| id.as_u32()),
+            [0x0000_00ff, 0x0100_0000, 0x6ddc_f6da]
+        );
+
+        ids.sort();
+        assert_eq!(
+            ids.map(|id| id.as_u32()),
+            [0x0100_0000, 0x6ddc_f6da, 0x0000_00ff]
+        );
+    }
+
+    #[test]
+    fn test_octets() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(v.octets(), [161, 162, 163, 164]);
+        assert_eq!(VolumeId32::from_octets(v.octets()), v);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_canonicalize_unique() {
+        let a = VolumeId32::parse("6ddcf6da").unwrap();
+        let b = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(
+            canonicalize_unique(&["a1a2a3a4", "6ddcf6da", "A1A2-A3A4", "6ddc-f6da", "a1a2a3a4"]),
+            Ok(crate::alloc::vec![b, a])
+        );
+        assert_eq!(canonicalize_unique(&[]), Ok(crate::alloc::vec![]));
+        assert_eq!(
+            canonicalize_unique(&["a1a2a3a4", "a1a2a3a4", "a1a2a3", "zz"]),
+            Err((2, Error(ErrorKind::ParseSimpleLength { len: 6 })))
+        );
+    }
+
+    #[test]
+    fn test_from_field() {
+        use crate::Endianness;
+
+        let bytes = [0xa1, 0xa2, 0xa3, 0xa4];
+
+        assert_eq!(
+            VolumeId32::from_field(bytes, Endianness::Little),
+            VolumeId32::from_bytes(bytes)
+        );
+        assert_eq!(
+            VolumeId32::from_field(bytes, Endianness::Big),
+            VolumeId32::from_bytes_be(bytes)
+        );
+        assert_eq!(
+            VolumeId32::from_field(bytes, Endianness::Big).display_value(),
+            VolumeId32::from_field(bytes, Endianness::Little).as_u32()
+        );
+    }
+
+    #[test]
+    fn test_xor_all() {
+        let a = VolumeId32::from_u32(0x6ddcf6da);
+        let b = VolumeId32::from_u32(0xa1a2a3a4);
+        let c = VolumeId32::from_u32(0x0000ffff);
+
+        let combined = VolumeId32::xor_all([a, b, c]);
+        assert_eq!(
+            combined,
+            VolumeId32::from_u32(0x6ddcf6da ^ 0xa1a2a3a4 ^ 0x0000ffff)
+        );
+        assert_eq!(VolumeId32::xor_all([c, a, b]), combined);
+        assert_eq!(VolumeId32::xor_all([b, c, a]), combined);
+        assert_eq!(VolumeId32::xor_all([a, a]), VolumeId32::nil());
+        assert_eq!(VolumeId32::xor_all([]), VolumeId32::nil());
+    }
+
+    #[test]
+    fn test_sum_wrapping() {
+        let a = VolumeId32::from_u32(0x6ddcf6da);
+        let b = VolumeId32::from_u32(0xa1a2a3a4);
+
+        assert_eq!(
+            VolumeId32::sum_wrapping([a, b]),
+            VolumeId32::from_u32(u32::wrapping_add(0x6ddcf6da, 0xa1a2a3a4))
+        );
+        assert_eq!(
+            VolumeId32::sum_wrapping([a, a]),
+            VolumeId32::from_u32(u32::wrapping_mul(0x6ddcf6da, 2))
+        );
+        assert_eq!(VolumeId32::sum_wrapping([]), VolumeId32::nil());
+    }
+
+    #[test]
+    fn test_from_bytes_ref() {
+        let bytes = [0xa1, 0xa2, 0xa3, 0xa4];
+        let v = VolumeId32::from_bytes_ref(&bytes);
+
+        assert_eq!(*v, VolumeId32::from_bytes(bytes));
+        assert_eq!(v.as_bytes(), &bytes);
+        assert!(crate::std::ptr::eq(v.as_bytes(), &bytes));
+    }
+
+    #[test]
+    fn test_is_all_same_byte() {
+        assert!(VolumeId32::nil().is_all_same_byte());
+        assert!(VolumeId32::max().is_all_same_byte());
+        assert!(VolumeId32::from_bytes([0x5a; 4]).is_all_same_byte());
+        assert!(!VolumeId32::from_u32(1).is_all_same_byte());
+        assert!(!VolumeId32::from_u32(1 << 31).is_all_same_byte());
+    }
+
+    #[test]
+    fn test_count_degenerate() {
+        let ids = [
+            VolumeId32::nil(),
+            VolumeId32::from_u32(0x6ddcf6da),
+            VolumeId32::max(),
+            VolumeId32::from_bytes([0x11; 4]),
+            VolumeId32::from_u32(1),
+        ];
+
+        assert_eq!(count_degenerate(&ids), 3);
+        assert_eq!(count_degenerate(&ids[1..2]), 0);
+        assert_eq!(count_degenerate(&[]), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_reader() {
+        use std::io::{Cursor, ErrorKind};
+
+        let mut cursor = Cursor::new([0xa1, 0xa2, 0xa3, 0xa4, 0xff]);
+        assert_eq!(
+            VolumeId32::from_reader(&mut cursor).unwrap(),
+            VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4])
+        );
+        assert_eq!(cursor.position(), 4);
+
+        let mut short = Cursor::new([0u8; 3]);
+        assert_eq!(
+            VolumeId32::from_reader(&mut short).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_writer() {
+        use crate::Endianness;
+        use std::vec::Vec;
+
+        let volumeid32 = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+
+        for order in [Endianness::Little, Endianness::Big] {
+            let mut buf = Vec::new();
+            volumeid32.to_writer(&mut buf, order).unwrap();
+            assert_eq!(buf.len(), 4);
+
+            let mut bytes = [0u8; 4];
+            bytes.copy_from_slice(&buf);
+            assert_eq!(VolumeId32::from_field(bytes, order), volumeid32);
+        }
+
+        let mut buf = Vec::new();
+        volumeid32.to_writer(&mut buf, Endianness::Little).unwrap();
+        assert_eq!(buf, [0xa1, 0xa2, 0xa3, 0xa4]);
+    }
+
+    #[test]
+    fn test_eq_u32() {
+        const VOLUMEID32: VolumeId32 = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+        const _: () = assert!(VOLUMEID32.eq_u32(0xa4a3a2a1));
+        const _: () = assert!(!VOLUMEID32.eq_u32(VOLUMEID32.display_value()));
+
+        assert!(VolumeId32::nil().eq_u32(0));
+        assert!(VolumeId32::max().eq_u32(u32::MAX));
+    }
+
+    #[test]
+    fn test_diff_positions() {
+        let a = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+        let b = VolumeId32::from_bytes([0xa1, 0xff, 0xa3, 0x00]);
+
+        assert!(a.diff_positions(&b).eq([1, 3]));
+        assert!(b.diff_positions(&a).eq([1, 3]));
+        assert_eq!(a.diff_positions(&a).next(), None);
+
+        // The iterator doesn't borrow either VolumeId32.
+        let positions = VolumeId32::nil().diff_positions(&VolumeId32::max());
+        assert_eq!(positions.count(), 4);
+    }
+
+    #[test]
+    fn test_distinct_byte_count() {
+        assert_eq!(
+            VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]).distinct_byte_count(),
+            4
+        );
+        assert_eq!(
+            VolumeId32::from_bytes([0xaa, 0xaa, 0xbb, 0xaa]).distinct_byte_count(),
+            2
+        );
+        assert_eq!(VolumeId32::nil().distinct_byte_count(), 1);
+        assert_eq!(VolumeId32::max().distinct_byte_count(), 1);
+        assert!(VolumeId32::from_bytes([0xaa; 4]).is_all_same_byte());
+    }
+
+    #[test]
+    fn test_to_bpb_field() {
+        // Bytes 0x40..0x52 of a FAT32 boot sector made by `mkfs.fat -i 6ddcf6da`:
+        // drive number, reserved, extended boot signature, serial and label.
+        let dump = [
+            0x80, 0x00, 0x29, 0xda, 0xf6, 0xdc, 0x6d, 0x4e, 0x4f, 0x20, 0x4e, 0x41, 0x4d, 0x45,
+            0x20, 0x20, 0x20, 0x20,
+        ];
+        let volumeid32 = VolumeId32::from_u32(0x6ddcf6da);
+
+        assert_eq!(volumeid32.to_bpb_field(), dump[3..7]);
+        assert_eq!(volumeid32.to_bpb_field(), volumeid32.into_bytes());
+
+        let mut bs = [0u8; 512];
+        bs[0x27..0x2b].copy_from_slice(&volumeid32.to_bpb_field());
+        assert_eq!(VolumeId32::from_fat16_sector(&bs), volumeid32);
+    }
+
+    #[test]
+    fn test_display_value() {
+        let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(volumeid32.display_value(), 0xa1a2a3a4);
+        assert_eq!(volumeid32.display_value(), volumeid32.as_u32().swap_bytes());
+
+        #[allow(deprecated)]
+        let be = volumeid32.as_u32_be();
+        assert_eq!(be, volumeid32.display_value());
+    }
+
+    #[test]
+    fn test_redact_nibbles() {
+        let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(volumeid32.redact_nibbles(0), volumeid32);
+        assert_eq!(volumeid32.redact_nibbles(u8::MAX), VolumeId32::nil());
+        assert_eq!(
+            volumeid32.redact_nibbles(0x0f),
+            VolumeId32::parse("0000a3a4").unwrap()
+        );
+        assert_eq!(
+            volumeid32.redact_nibbles(0b1000_0001),
+            VolumeId32::parse("01a2a3a0").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_content_hash() {
+        let data = [0x55u8, 0xaa, 0x00, 0xff];
+
+        assert_eq!(
+            VolumeId32::from_content_hash(&data),
+            VolumeId32::from_content_hash(&data)
+        );
+        assert_ne!(
+            VolumeId32::from_content_hash(&data),
+            VolumeId32::from_content_hash(&data[..3])
+        );
+        assert_eq!(VolumeId32::from_content_hash(&[]).as_u32(), 0x84222325);
+        assert_eq!(
+            VolumeId32::from_content_hash(b"backup-disk"),
+            VolumeId32::from_label("backup-disk")
+        );
+    }
+
+    #[test]
+    fn test_with_parity() {
+        let volumeid32 = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+        let bytes = volumeid32.with_parity();
+
+        assert_eq!(bytes, [0xa1, 0xa2, 0xa3, 0xa4, 0x04]);
+        assert_eq!(VolumeId32::from_parity_checked(&bytes), Ok(volumeid32));
+        assert_eq!(VolumeId32::nil().with_parity(), [0; 5]);
+
+        let mut corrupted = bytes;
+        corrupted[2] ^= 0x01;
+        assert_eq!(
+            VolumeId32::from_parity_checked(&corrupted),
+            Err(Error(ErrorKind::ParseParity {
+                expected: 0x04 ^ 0x01,
+                found: 0x04,
+            }))
+        );
+
+        let mut corrupted = bytes;
+        corrupted[4] = 0xff;
+        assert!(VolumeId32::from_parity_checked(&corrupted).is_err());
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert!(volumeid32.matches_pattern("a1a2a3a4"));
+        assert!(volumeid32.matches_pattern("a1??a3a4"));
+        assert!(volumeid32.matches_pattern("A1A2????"));
+        assert!(volumeid32.matches_pattern("????????"));
+
+        assert!(!volumeid32.matches_pattern("b1??????"));
+        assert!(!volumeid32.matches_pattern("gggggggg"));
+
+        assert!(!volumeid32.matches_pattern("1??a3a4"));
+        assert!(!volumeid32.matches_pattern("a1??a3a4?"));
+        assert!(!volumeid32.matches_pattern(""));
+    }
+
+    #[test]
+    fn test_eq_byteswapped() {
+        let a = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+
+        assert!(a.eq_byteswapped(&VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4])));
+        assert!(a.eq_byteswapped(&VolumeId32::from_bytes([0xa4, 0xa3, 0xa2, 0xa1])));
+        assert!(VolumeId32::from_bytes([0xa4, 0xa3, 0xa2, 0xa1]).eq_byteswapped(&a));
+        assert!(a.eq_byteswapped(&VolumeId32::from_bytes_be(*a.as_bytes())));
+
+        assert!(!a.eq_byteswapped(&VolumeId32::from_bytes([0xa1, 0xa2, 0xa4, 0xa3])));
+        assert!(!a.eq_byteswapped(&VolumeId32::max()));
+    }
+
+    #[test]
+    fn test_to_rgb() {
+        let volumeid32 = VolumeId32::from_bytes([0x10, 0x20, 0x30, 0x01]);
+
+        assert_eq!(volumeid32.to_rgb(), (0x11, 0x20, 0x30));
+        assert_eq!(volumeid32.to_rgb(), volumeid32.to_rgb());
+        assert_eq!(
+            VolumeId32::from_label("disk").to_rgb(),
+            VolumeId32::from_label("disk").to_rgb()
+        );
+        assert_eq!(VolumeId32::nil().to_rgb(), (0, 0, 0));
+        assert_ne!(VolumeId32::from_u32(1).to_rgb(), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_nil_max_str() {
+        use std::string::ToString;
+
+        assert_eq!(VolumeId32::nil().to_string(), VolumeId32::NIL_STR);
+        assert_eq!(VolumeId32::max().to_string(), VolumeId32::MAX_STR);
+        assert_eq!(&*VolumeId32::nil().simple_str(), VolumeId32::NIL_STR);
+        assert_eq!(
+            VolumeId32::parse(VolumeId32::NIL_STR),
+            Ok(VolumeId32::nil())
+        );
+        assert_eq!(
+            &*VolumeId32::nil().hyphenated_str(),
+            VolumeId32::NIL_HYPHENATED_STR
+        );
+        assert_eq!(
+            &*VolumeId32::max().hyphenated_str(),
+            VolumeId32::MAX_HYPHENATED_STR
+        );
+        assert_eq!(
+            VolumeId32::parse(VolumeId32::MAX_HYPHENATED_STR),
+            Ok(VolumeId32::max())
+        );
+    }
+
+    #[test]
+    fn test_as_i32() {
+        let volumeid32 = VolumeId32::from_u32(0xa1a2a3a4);
+
+        assert_eq!(volumeid32.as_i32(), -1583176796);
+        assert_eq!(VolumeId32::from_i32(-1583176796), volumeid32);
+        assert_eq!(VolumeId32::from_u32(42).as_i32(), 42);
+        assert_eq!(VolumeId32::nil().as_i32(), 0);
+        assert_eq!(VolumeId32::max().as_i32(), -1);
+        assert_eq!(VolumeId32::from_i32(i32::MIN).as_u32(), 1 << 31);
+    }
+
+    #[test]
+    fn test_in_range() {
+        let low = VolumeId32::from_u32(0x0000_00ff);
+        let high = VolumeId32::from_u32(0x00ff_0000);
+
+        assert!(VolumeId32::from_u32(0x0000_ff00).in_range(low, high));
+        assert!(low.in_range(low, high));
+        assert!(high.in_range(low, high));
+        assert!(low.in_range(low, low));
+
+        assert!(!VolumeId32::nil().in_range(low, high));
+        assert!(!VolumeId32::max().in_range(low, high));
+        assert!(!VolumeId32::from_u32(0x0000_ff00).in_range(high, low));
+
+        // The stored bytes of low sort after those of the middle value, but
+        // the range uses the numeric value.
+        assert!(low > VolumeId32::from_u32(0x0000_ff00));
     }
 }
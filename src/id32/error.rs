@@ -1,4 +1,8 @@
-use crate::std::{fmt, str::from_utf8};
+use crate::{
+    common::HexError,
+    id32::{fmt::HyphenatedId32, parser::parse_separatedid32},
+    std::{fmt, str::from_utf8},
+};
 
 /// A general error that can occur when working with VolumeId32s.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -28,6 +32,56 @@ pub(crate) enum ErrorKind {
         len: usize,
         index: usize,
     },
+    /// A hyphenated [`VolumeId32`] had its separator in the wrong place
+    ///
+    /// [`VolumeId32`]: ../struct.VolumeId32.html
+    ParseSeparatorPosition { index: usize },
+    /// Invalid character in an alternative encoding of a [`VolumeId32`]
+    ///
+    /// [`VolumeId32`]: ../struct.VolumeId32.html
+    ParseEncodedChar {
+        character: char,
+        index: usize,
+        expected: &'static str,
+    },
+    /// An alternative encoding of a [`VolumeId32`] had the wrong length
+    ///
+    /// [`VolumeId32`]: ../struct.VolumeId32.html
+    ParseEncodedLength { len: usize, expected: usize },
+    /// The decoded value doesn't fit in a [`VolumeId32`]
+    ///
+    /// [`VolumeId32`]: ../struct.VolumeId32.html
+    ParseOutOfRange,
+    /// A boot sector was too short to contain the serial field
+    BootSectorLength { len: usize, expected: usize },
+    /// The input didn't start with the expected prefix
+    ParsePrefix { expected: &'static str },
+    /// A hexdump line didn't have enough bytes
+    HexdumpLength { len: usize, expected: usize },
+    /// An ISO 9660 volume descriptor was too short
+    DescriptorLength { len: usize, expected: usize },
+    /// An ISO 9660 volume descriptor had an unsupported type
+    DescriptorType { found: u8 },
+    /// The input had a mismatched or nested wrapper
+    ParseWrapper,
+    /// A word list wasn't a power of two in length
+    WordlistLength { len: usize },
+    /// A word code contained a word that isn't in the word list
+    ParseWord { index: usize },
+    /// An exFAT boot sector had an invalid bytes per sector shift
+    SectorShift { shift: u8 },
+    /// A parity byte didn't match the bytes it protects
+    ParseParity { expected: u8, found: u8 },
+    /// The input didn't contain the expected field
+    ParseMissingField { field: &'static str },
+    /// The input didn't contain the expected delimiter
+    ParseMissingDelimiter { delimiter: char },
+    /// The key of a `key=value` pair didn't match the expected key
+    ParseKey,
+    /// A destination buffer was too small to hold the bytes
+    BufferTooSmall { len: usize, needed: usize },
+    /// An ISO 9660 volume descriptor didn't have the standard identifier
+    DescriptorIdentifier,
     /// The input was not a valid UTF8 string
     ParseInvalidUTF8,
 }
@@ -45,6 +99,30 @@ pub struct InvalidVolumeId32<'a>(pub(crate) &'a [u8]);
 impl<'a> InvalidVolumeId32<'a> {
     /// Converts the lightweight error type into detailed diagnostics.
     pub fn into_err(self) -> Error {
+        self.into_err_with_separators(b"-")
+    }
+
+    /// Converts the lightweight error type into detailed diagnostics, using the
+    /// problem that [`try_parse_hex_detailed`] found in simple format input.
+    ///
+    /// [`try_parse_hex_detailed`]: crate::try_parse_hex_detailed
+    pub(crate) fn into_err_with_hex(self, err: HexError) -> Error {
+        match err.byte {
+            Some(byte) if !byte.is_ascii_hexdigit() => match from_utf8(self.0) {
+                // Every byte before the problem is a hex digit, so it starts a char
+                Ok(s) => Error(ErrorKind::ParseChar {
+                    character: s[err.index - 1..].chars().next().unwrap_or(byte as char),
+                    index: err.index,
+                }),
+                Err(_) => Error(ErrorKind::ParseInvalidUTF8),
+            },
+            _ => Error(ErrorKind::ParseSimpleLength { len: self.0.len() }),
+        }
+    }
+
+    /// Converts the lightweight error type into detailed diagnostics, treating
+    /// any byte in `seps` as a group separator.
+    pub(crate) fn into_err_with_separators(self, seps: &[u8]) -> Error {
         // Check whether or not the input was ever actually a valid UTF8 string
         let input_str = match from_utf8(self.0) {
             Ok(s) => s,
@@ -62,7 +140,7 @@ impl<'a> InvalidVolumeId32<'a> {
                     character,
                     index: index + 1,
                 });
-            } else if byte == b'-' {
+            } else if seps.contains(&byte) {
                 // While we search, also count group breaks
                 if hyphen_count < 1 {
                     group_bounds = index;
@@ -77,6 +155,18 @@ impl<'a> InvalidVolumeId32<'a> {
             }
         }
 
+        // A complete hyphenated VolumeId32 with trailing characters, so the
+        // last group is too long
+        if input_str.len() > HyphenatedId32::LENGTH
+            && parse_separatedid32(&self.0[..HyphenatedId32::LENGTH], seps).is_ok()
+        {
+            return Error(ErrorKind::ParseGroupLength {
+                group: 1,
+                len: input_str.len() - 5,
+                index: 6,
+            });
+        }
+
         if hyphen_count == 0 {
             // This means that we tried and failed to parse a simpleid32.
             // Since we verified that all the characters are valid, this means
@@ -93,6 +183,14 @@ impl<'a> InvalidVolumeId32<'a> {
         } else {
             // There are 2 groups, one of them has an incorrect length
             const BLOCK_STARTS: [usize; 2] = [0, 5];
+            if input_str.len() == HyphenatedId32::LENGTH && group_bounds != BLOCK_STARTS[1] - 1 {
+                // The input is the right length, so the separator itself
+                // is what's out of place
+                return Error(ErrorKind::ParseSeparatorPosition {
+                    index: group_bounds + 1,
+                });
+            }
+
             if group_bounds != BLOCK_STARTS[1] - 1 {
                 return Error(ErrorKind::ParseGroupLength {
                     group: 0,
@@ -144,6 +242,108 @@ impl fmt::Display for Error {
                     group, expected, len
                 )
             }
+            ErrorKind::ParseSeparatorPosition { index } => {
+                write!(
+                    f,
+                    "invalid separator position: unexpected separator at {}",
+                    index
+                )
+            }
+            ErrorKind::ParseEncodedChar {
+                character,
+                index,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "invalid character: expected {}, found `{}` at {}",
+                    expected, character, index
+                )
+            }
+            ErrorKind::ParseEncodedLength { len, expected } => {
+                write!(f, "invalid length: expected {}, found {}", expected, len)
+            }
+            ErrorKind::ParseOutOfRange => write!(f, "invalid value: out of range"),
+            ErrorKind::BootSectorLength { len, expected } => {
+                write!(
+                    f,
+                    "invalid boot sector length: expected at least {}, found {}",
+                    expected, len
+                )
+            }
+            ErrorKind::ParsePrefix { expected } => {
+                write!(f, "invalid prefix: expected `{}`", expected)
+            }
+            ErrorKind::HexdumpLength { len, expected } => {
+                write!(
+                    f,
+                    "invalid hexdump line: expected at least {} bytes, found {}",
+                    expected, len
+                )
+            }
+            ErrorKind::DescriptorLength { len, expected } => {
+                write!(
+                    f,
+                    "invalid volume descriptor length: expected {}, found {}",
+                    expected, len
+                )
+            }
+            ErrorKind::DescriptorType { found } => {
+                write!(
+                    f,
+                    "invalid volume descriptor type: expected 1 or 2, found {}",
+                    found
+                )
+            }
+            ErrorKind::ParseWrapper => {
+                write!(f, "invalid wrapper: expected a single matching pair")
+            }
+            ErrorKind::WordlistLength { len } => {
+                write!(
+                    f,
+                    "invalid word list length: expected a power of two of at least 2, found {}",
+                    len
+                )
+            }
+            ErrorKind::ParseWord { index } => {
+                write!(f, "invalid word code: unknown word at {}", index)
+            }
+            ErrorKind::SectorShift { shift } => {
+                write!(
+                    f,
+                    "invalid bytes per sector shift: expected 9 to 12, found {}",
+                    shift
+                )
+            }
+            ErrorKind::ParseParity { expected, found } => {
+                write!(
+                    f,
+                    "invalid parity: expected {:#04x}, found {:#04x}",
+                    expected, found
+                )
+            }
+            ErrorKind::ParseMissingField { field } => {
+                write!(f, "missing field: expected `{}`", field)
+            }
+            ErrorKind::ParseMissingDelimiter { delimiter } => {
+                write!(f, "missing delimiter: expected `{}`", delimiter)
+            }
+            ErrorKind::ParseKey => {
+                write!(f, "invalid key: didn't match the expected key")
+            }
+            ErrorKind::BufferTooSmall { len, needed } => {
+                write!(
+                    f,
+                    "buffer too small: expected at least {} bytes, found {}",
+                    needed, len
+                )
+            }
+            ErrorKind::DescriptorIdentifier => {
+                write!(
+                    f,
+                    "invalid volume descriptor: expected standard identifier `CD001`"
+                )
+            }
             ErrorKind::ParseInvalidUTF8 => write!(f, "non-UTF8 input"),
         }
     }
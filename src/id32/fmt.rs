@@ -13,17 +13,24 @@
 //! Adapters for alternative string formats.
 
 use crate::{
-    common::{LOWER, UPPER},
-    id32::{Error, VolumeId32},
+    common::{
+        FormatKind, IdStr, LOWER, UPPER, base64url_encode, eq_ignore_ascii_case, write_separated,
+    },
+    id32::{Error, VolumeId32, error::ErrorKind},
     std::{borrow::Borrow, fmt, mem::transmute, str::FromStr},
 };
 
 #[cfg(feature = "alloc")]
-use crate::alloc::string::{String, ToString};
+use crate::alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 impl fmt::Debug for VolumeId32 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        return fmt::LowerHex::fmt(&self, f);
+        return write!(f, "VolumeId32({:x})", self);
     }
 }
 
@@ -90,6 +97,34 @@ pub struct SimpleId32(VolumeId32);
 #[repr(transparent)]
 pub struct HyphenatedId32(VolumeId32);
 
+/// Format a [`VolumeId32`] using a [`FormatKind`] chosen at runtime.
+///
+/// Returned by [`VolumeId32::display_as`].
+///
+/// [`FormatKind`]: crate::FormatKind
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Formatted<'a>(&'a VolumeId32, FormatKind, bool);
+
+/// Format a [`VolumeId32`] as dotted decimal octets, like
+/// `161.162.163.164`.
+///
+/// Returned by [`VolumeId32::dotted`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Octets(VolumeId32);
+
+/// A [`VolumeId32`] that remembers the case and format of the string it was
+/// parsed from.
+///
+/// Returned by [`VolumeId32::parse_cased`]. Its `Display` reproduces the
+/// original style, so tools can echo user input without silently normalizing
+/// it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CasedVolumeId32 {
+    pub(crate) volumeid32: VolumeId32,
+    pub(crate) upper: bool,
+    pub(crate) hyphenated: bool,
+}
+
 impl VolumeId32 {
     /// Get a [`SimpleId32`] formatter.
     #[inline]
@@ -99,7 +134,7 @@ impl VolumeId32 {
 
     /// Get a borrowed [`SimpleId32`] formatter.
     #[inline]
-    pub fn as_simple(&self) -> &SimpleId32 {
+    pub const fn as_simple(&self) -> &SimpleId32 {
         unsafe { transmute(self) }
     }
 
@@ -111,9 +146,674 @@ impl VolumeId32 {
 
     /// Get a borrowed [`HyphenatedId32`] formatter.
     #[inline]
-    pub fn as_hyphenated(&self) -> &HyphenatedId32 {
+    pub const fn as_hyphenated(&self) -> &HyphenatedId32 {
         unsafe { transmute(self) }
     }
+
+    /// Returns the VolumeId32 as a lower-case simple string, stored inline
+    /// without needing an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    ///
+    /// assert_eq!(&*volumeid32.simple_str(), "6ddcf6da");
+    /// ```
+    #[inline]
+    pub const fn simple_str(&self) -> IdStr<{ SimpleId32::LENGTH }> {
+        IdStr::new(format_simpleid32(&self.0, false), SimpleId32::LENGTH)
+    }
+
+    /// Writes the VolumeId32 as an upper-case simple string to `buffer`, and
+    /// returns the subslice of the buffer that contains it.
+    ///
+    /// This is the same as `self.simple().encode_upper(buffer)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`SimpleId32::LENGTH`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    /// let mut buf = [0u8; 8];
+    ///
+    /// assert_eq!(volumeid32.encode_upper(&mut buf), "6DDCF6DA");
+    /// ```
+    ///
+    /// [`SimpleId32::LENGTH`]: SimpleId32::LENGTH
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        self.as_simple().encode_upper(buffer)
+    }
+
+    /// Returns the VolumeId32 as a lower-case hyphenated string, stored inline
+    /// without needing an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    ///
+    /// assert_eq!(&*volumeid32.hyphenated_str(), "6ddc-f6da");
+    /// ```
+    #[inline]
+    pub const fn hyphenated_str(&self) -> IdStr<{ HyphenatedId32::LENGTH }> {
+        IdStr::new(
+            format_hyphenatedid32(&self.0, false),
+            HyphenatedId32::LENGTH,
+        )
+    }
+
+    /// Get a [`Formatted`] adapter that displays the VolumeId32 using `kind`,
+    /// with upper-case hex digits if `upper` is set.
+    ///
+    /// This is useful when the format is only known at runtime, for example
+    /// when it is picked by a command line flag.
+    /// [`FormatKind::Auto`] uses the hyphenated format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::{FormatKind, id32::VolumeId32};
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid32.display_as(FormatKind::Hyphenated, true).to_string(),
+    ///     "6DDC-F6DA",
+    /// );
+    /// assert_eq!(
+    ///     volumeid32.display_as(FormatKind::Colon, false).to_string(),
+    ///     "6d:dc:f6:da",
+    /// );
+    /// ```
+    ///
+    /// [`FormatKind::Auto`]: crate::FormatKind::Auto
+    #[inline]
+    pub const fn display_as(&self, kind: FormatKind, upper: bool) -> Formatted<'_> {
+        Formatted(self, kind, upper)
+    }
+
+    /// Get an [`Octets`] formatter that displays the VolumeId32 as dotted
+    /// decimal octets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.dotted().to_string(), "161.162.163.164");
+    /// ```
+    #[inline]
+    pub const fn dotted(self) -> Octets {
+        Octets(self)
+    }
+
+    /// Writes the VolumeId32 as hex digits to `w`, inserting `sep` after every
+    /// `group` digits, with upper-case hex digits if `upper` is set.
+    ///
+    /// A `group` of 0, or one that covers all 8 digits, writes the simple
+    /// format with no separators. This doesn't need an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    /// let mut s = String::new();
+    ///
+    /// volumeid32.write_grouped(&mut s, 4, '-', true).unwrap();
+    ///
+    /// assert_eq!(s, "6DDC-F6DA");
+    /// ```
+    pub fn write_grouped<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        group: usize,
+        sep: char,
+        upper: bool,
+    ) -> fmt::Result {
+        let hex = format_simpleid32(&self.0, upper);
+
+        for (i, &c) in hex.iter().enumerate() {
+            if group > 0 && i > 0 && i % group == 0 {
+                w.write_char(sep)?;
+            }
+            w.write_char(c as char)?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl VolumeId32 {
+    /// Returns a filename for the VolumeId32, like `fat_a1a2a3a4.img`, for
+    /// naming disk image dumps.
+    ///
+    /// The serial is written in lower-case simple form. Any character of
+    /// `prefix` or `ext` that isn't an ASCII letter, digit, `-` or `_` is
+    /// replaced with `_`, so the name is safe on every common filesystem. An
+    /// empty `prefix` or `ext` is left out along with its separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("A1A2A3A4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.to_filename("fat", "img"), "fat_a1a2a3a4.img");
+    /// assert_eq!(volumeid32.to_filename("", ".bin"), "a1a2a3a4.bin");
+    /// ```
+    pub fn to_filename(&self, prefix: &str, ext: &str) -> String {
+        crate::common::filename(prefix, &self.simple_str(), ext)
+    }
+
+    /// Parses `input` and returns it in the canonical lower-case simple form.
+    ///
+    /// If `input` is already canonical it is returned as [`Cow::Borrowed`]
+    /// without allocating, otherwise a new string is returned as
+    /// [`Cow::Owned`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` can't be parsed with
+    /// [`parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// # use std::borrow::Cow;
+    /// assert!(matches!(
+    ///     VolumeId32::normalize_cow("6ddcf6da").unwrap(),
+    ///     Cow::Borrowed("6ddcf6da"),
+    /// ));
+    /// assert_eq!(
+    ///     VolumeId32::normalize_cow("6DDC-F6DA").unwrap(),
+    ///     Cow::<str>::Owned("6ddcf6da".into()),
+    /// );
+    /// ```
+    ///
+    /// [`parse`]: ../struct.VolumeId32.html#method.parse
+    pub fn normalize_cow(input: &str) -> Result<Cow<'_, str>, Error> {
+        let volumeid32 = VolumeId32::parse(input)?;
+
+        if input.as_bytes() == format_simpleid32(&volumeid32.0, false) {
+            return Ok(Cow::Borrowed(input));
+        }
+
+        Ok(Cow::Owned(volumeid32.to_string()))
+    }
+
+    /// Returns the [`as_u32`] value of the VolumeId32 as a lower-case base-36
+    /// string.
+    ///
+    /// The result is left-padded with zeros to 7 digits, which is enough to
+    /// hold any 32bit value, so it always has the same length. It can be
+    /// parsed back with [`from_base36_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// assert_eq!(VolumeId32::from_u32(35).to_base36(), "000000z");
+    /// assert_eq!(VolumeId32::max().to_base36(), "1z141z3");
+    /// ```
+    ///
+    /// [`as_u32`]: ../struct.VolumeId32.html#method.as_u32
+    /// [`from_base36_str`]: ../struct.VolumeId32.html#method.from_base36_str
+    pub fn to_base36(&self) -> String {
+        let mut buf = ['0'; 7];
+        let mut value = self.as_u32();
+        let mut i = buf.len();
+
+        while value > 0 {
+            i -= 1;
+            buf[i] = char::from_digit(value % 36, 36).unwrap();
+            value /= 36;
+        }
+
+        buf.iter().collect()
+    }
+
+    /// Returns the four bytes of the VolumeId32 as a dotted decimal string,
+    /// like an IPv4 address.
+    ///
+    /// The bytes are written in the order they are stored, and can be parsed
+    /// back with [`from_dotted_decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.to_dotted_decimal(), "161.162.163.164");
+    /// ```
+    ///
+    /// [`from_dotted_decimal`]: ../struct.VolumeId32.html#method.from_dotted_decimal
+    pub fn to_dotted_decimal(&self) -> String {
+        self.dotted().to_string()
+    }
+
+    /// Returns the VolumeId32 zero-extended to 16 bytes and formatted as a
+    /// hyphenated UUID, like `00000000-0000-0000-0000-0000a1a2a3a4`.
+    ///
+    /// This is for storage that only accepts UUID-shaped strings. The result
+    /// isn't a valid RFC 4122 UUID, since its version and variant bits are
+    /// zero. It can be parsed back with [`from_uuid_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid32.to_uuid_string(),
+    ///     "00000000-0000-0000-0000-0000a1a2a3a4",
+    /// );
+    /// ```
+    ///
+    /// [`from_uuid_str`]: ../struct.VolumeId32.html#method.from_uuid_str
+    pub fn to_uuid_string(&self) -> String {
+        format!("00000000-0000-0000-0000-0000{}", self)
+    }
+
+    /// Returns the VolumeId32 as a `blkid` `UUID=` field, like
+    /// `UUID="6DDC-F6DA"`.
+    ///
+    /// Like `vol`, `blkid` shows the upper-case hyphenated [`as_u32`] value
+    /// rather than the stored bytes. The field can be parsed back with
+    /// [`parse_blkid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_u32(0x6ddcf6da);
+    ///
+    /// assert_eq!(volumeid32.to_blkid_string(), r#"UUID="6DDC-F6DA""#);
+    /// ```
+    ///
+    /// [`as_u32`]: ../struct.VolumeId32.html#method.as_u32
+    /// [`parse_blkid`]: ../struct.VolumeId32.html#method.parse_blkid
+    pub fn to_blkid_string(&self) -> String {
+        let value = self.as_u32();
+        format!(
+            "{}\"{:04X}-{:04X}\"",
+            BLKID_FIELD,
+            value >> 16,
+            value & 0xffff
+        )
+    }
+
+    /// Returns the 32 bits of the VolumeId32 as a string of `0` and `1`
+    /// characters, for rendering the serial as a bit pattern.
+    ///
+    /// See [`encode_bits`] for the bit order, and for a version that doesn't
+    /// need an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid32.to_bit_string(),
+    ///     "10100001101000101010001110100100",
+    /// );
+    /// ```
+    ///
+    /// [`encode_bits`]: #method.encode_bits
+    pub fn to_bit_string(&self) -> String {
+        let mut buf = [0u8; 32];
+        self.encode_bits(&mut buf).to_string()
+    }
+
+    /// Returns the VolumeId32 as an upper-case simple string.
+    ///
+    /// This is the same as `format!("{:X}", self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    ///
+    /// assert_eq!(volumeid32.to_uppercase_string(), "6DDCF6DA");
+    /// ```
+    pub fn to_uppercase_string(&self) -> String {
+        let mut buf = [0u8; SimpleId32::LENGTH];
+        self.encode_upper(&mut buf).to_string()
+    }
+
+    /// Returns the VolumeId32 as a lower-case simple string together with its
+    /// stored bytes, for logging both forms from one value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// let (s, bytes) = volumeid32.dump();
+    ///
+    /// assert_eq!(s, "a1a2a3a4");
+    /// assert_eq!(bytes, [0xa1, 0xa2, 0xa3, 0xa4]);
+    /// ```
+    pub fn dump(&self) -> (String, [u8; 4]) {
+        (self.simple_str().to_string(), self.0)
+    }
+
+    /// Returns [`as_u32`] as a `0x` prefixed hex literal, for generating C
+    /// or Rust source code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.to_c_literal(), "0xa4a3a2a1");
+    /// ```
+    ///
+    /// [`as_u32`]: ../struct.VolumeId32.html#method.as_u32
+    pub fn to_c_literal(&self) -> String {
+        use fmt::Write;
+
+        let mut s = String::with_capacity(10);
+        write!(s, "{:#010x}", self.as_u32()).unwrap();
+
+        s
+    }
+
+    /// Returns the stored bytes as a Rust array literal, for generating Rust
+    /// source code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid32.to_rust_bytes_literal(),
+    ///     "[0xa1, 0xa2, 0xa3, 0xa4]",
+    /// );
+    /// ```
+    pub fn to_rust_bytes_literal(&self) -> String {
+        use fmt::Write;
+
+        let mut s = String::with_capacity(4 * 6);
+
+        s.push('[');
+        for (i, byte) in self.0.iter().enumerate() {
+            if i > 0 {
+                s.push_str(", ");
+            }
+            write!(s, "{:#04x}", byte).unwrap();
+        }
+        s.push(']');
+
+        s
+    }
+
+    /// Returns the stored bytes in reverse order, as a lower-case hex string.
+    ///
+    /// This is the hex of [`to_bytes_be`], which is also the [`as_u32`] value
+    /// shown by `vol` and `blkid`, and is the reverse of [`to_string`], which
+    /// shows the bytes in the order the VolumeId32 stores them. It is useful
+    /// when a raw dump of the field was written out as a little-endian integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.to_stored_hex_string(), "a4a3a2a1");
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    ///
+    /// [`to_bytes_be`]: ../struct.VolumeId32.html#method.to_bytes_be
+    /// [`as_u32`]: ../struct.VolumeId32.html#method.as_u32
+    /// [`to_string`]: crate::alloc::string::ToString::to_string
+    pub fn to_stored_hex_string(&self) -> String {
+        VolumeId32::from_bytes(self.to_bytes_be())
+            .simple_str()
+            .to_string()
+    }
+
+    /// Returns the VolumeId32 as a sequence of words from `wordlist`, for
+    /// showing a serial in a form that is easier to remember.
+    ///
+    /// `wordlist` must have a power of two length of at least 2. Each word
+    /// encodes `log2(wordlist.len())` bits of [`as_u32`], most significant
+    /// first, so a list of 2048 words gives three words. The words can be
+    /// turned back into the VolumeId32 with [`from_wordcode`] and the same
+    /// word list.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the length of `wordlist` isn't a
+    /// power of two of at least 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let wordlist = [
+    ///     "amber", "birch", "cedar", "delta", "ember", "fjord", "grove", "haven",
+    ///     "iris", "jade", "kelp", "lotus", "maple", "north", "olive", "pearl",
+    /// ];
+    /// let volumeid32 = VolumeId32::from_u32(0x6ddcf6da);
+    ///
+    /// let words = volumeid32.to_wordcode(&wordlist).unwrap();
+    ///
+    /// assert_eq!(words[..2], ["grove", "north"]);
+    /// assert_eq!(VolumeId32::from_wordcode(&words, &wordlist), Ok(volumeid32));
+    /// ```
+    ///
+    /// [`as_u32`]: ../struct.VolumeId32.html#method.as_u32
+    /// [`from_wordcode`]: ../struct.VolumeId32.html#method.from_wordcode
+    pub fn to_wordcode<'a>(&self, wordlist: &[&'a str]) -> Result<Vec<&'a str>, Error> {
+        let bits = wordcode_bits(wordlist.len())?;
+        let count = 32u32.div_ceil(bits);
+        let value = self.as_u32() as u64;
+
+        Ok((0..count)
+            .rev()
+            .map(|i| wordlist[((value >> (i * bits)) & ((1 << bits) - 1)) as usize])
+            .collect())
+    }
+}
+
+impl VolumeId32 {
+    /// Returns the VolumeId32 as the decimal serial some localized versions of
+    /// Windows print, stored inline without needing an allocator.
+    ///
+    /// The decimal is the [`as_u32`] value, the same value `vol` shows as
+    /// `HHHH-HHHH`. This is not [`display_value`]: the VolumeId32 displays its
+    /// stored bytes in order, while `vol` shows them little-endian, so only
+    /// [`as_u32`] matches what Windows prints. It can be parsed back with
+    /// [`from_windows_decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_u32(0x6ddcf6da);
+    ///
+    /// assert_eq!(volumeid32.to_windows_decimal(), "1843197658");
+    /// ```
+    ///
+    /// [`as_u32`]: ../struct.VolumeId32.html#method.as_u32
+    /// [`display_value`]: ../struct.VolumeId32.html#method.display_value
+    /// [`from_windows_decimal`]: ../struct.VolumeId32.html#method.from_windows_decimal
+    pub const fn to_windows_decimal(&self) -> IdStr<10> {
+        let mut value = self.as_u32();
+        let mut digits = [0u8; 10];
+        let mut len = 0;
+
+        loop {
+            digits[len] = b'0' + (value % 10) as u8;
+            value /= 10;
+            len += 1;
+
+            if value == 0 {
+                break;
+            }
+        }
+
+        let mut buf = [0u8; 10];
+        let mut i = 0;
+        while i < len {
+            buf[i] = digits[len - 1 - i];
+            i += 1;
+        }
+
+        IdStr::new(buf, len)
+    }
+}
+
+impl VolumeId32 {
+    /// Writes the 32 bits of the VolumeId32 to `buffer` as `0` and `1`
+    /// characters, and returns the buffer as a string.
+    ///
+    /// The bits are written most significant first, with the bytes in the
+    /// order they are stored, so each group of four characters matches one
+    /// hex digit of the [`Display`] format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    /// let mut buf = [0u8; 32];
+    ///
+    /// assert_eq!(
+    ///     volumeid32.encode_bits(&mut buf),
+    ///     "10100001101000101010001110100100",
+    /// );
+    /// ```
+    ///
+    /// [`Display`]: core::fmt::Display
+    pub fn encode_bits<'buf>(&self, buffer: &'buf mut [u8; 32]) -> &'buf mut str {
+        for (i, bit) in buffer.iter_mut().enumerate() {
+            *bit = if self.0[i / 8] & (0x80 >> (i % 8)) != 0 {
+                b'1'
+            } else {
+                b'0'
+            };
+        }
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buffer) }
+    }
+}
+
+/// Returns the number of bits each word of a word code encodes, for a word
+/// list of length `len`.
+pub(crate) const fn wordcode_bits(len: usize) -> Result<u32, Error> {
+    if len < 2 || !len.is_power_of_two() {
+        return Err(Error(ErrorKind::WordlistLength { len }));
+    }
+
+    let bits = len.trailing_zeros();
+    Ok(if bits > 32 { 32 } else { bits })
+}
+
+/// The name of the field `blkid` prints a FAT serial in.
+pub(crate) const BLKID_FIELD: &str = "UUID=";
+
+/// The prefix of a [`VolumeId32`] log token.
+pub(crate) const LOG_TOKEN_PREFIX: &str = "vid32:";
+
+impl VolumeId32 {
+    /// Returns the VolumeId32 as a prefixed token for logs, like
+    /// `vid32:a1a2a3a4`.
+    ///
+    /// The prefix keeps serials from being confused with other hex values
+    /// and makes them easy to find with `grep`. The token can be parsed back
+    /// with [`from_log_token`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.to_log_token(), "vid32:a1a2a3a4");
+    /// ```
+    ///
+    /// [`from_log_token`]: ../struct.VolumeId32.html#method.from_log_token
+    pub const fn to_log_token(&self) -> IdStr<14> {
+        let prefix = LOG_TOKEN_PREFIX.as_bytes();
+        let hex = format_simpleid32(&self.0, false);
+        let mut buf = [0u8; 14];
+        let mut i = 0;
+
+        while i < 14 {
+            buf[i] = if i < prefix.len() {
+                prefix[i]
+            } else {
+                hex[i - prefix.len()]
+            };
+            i += 1;
+        }
+
+        IdStr::new(buf, 14)
+    }
+
+    /// Returns the VolumeId32 as a token for QR codes, the 8 upper-case hex
+    /// digits of the simple format, like `6DDCF6DA`.
+    ///
+    /// The token only contains `0`-`9` and `A`-`F`, so it always fits the QR
+    /// alphanumeric mode, which is more compact than byte mode. It can be
+    /// parsed back with [`from_qr_token`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("6ddcf6da").unwrap();
+    ///
+    /// assert_eq!(volumeid32.to_qr_token(), "6DDCF6DA");
+    /// ```
+    ///
+    /// [`from_qr_token`]: ../struct.VolumeId32.html#method.from_qr_token
+    pub const fn to_qr_token(&self) -> IdStr<{ SimpleId32::LENGTH }> {
+        IdStr::new(format_simpleid32(&self.0, true), SimpleId32::LENGTH)
+    }
+
+    /// Returns the 4 stored bytes of the VolumeId32 as 6 characters of
+    /// unpadded base64url, like `oaKjpA` for `a1a2a3a4`.
+    ///
+    /// This is more compact than hex when embedding a serial in a URL. It can
+    /// be parsed back with [`from_base64url_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+    ///
+    /// assert_eq!(volumeid32.to_base64url(), "oaKjpA");
+    /// ```
+    ///
+    /// [`from_base64url_str`]: ../struct.VolumeId32.html#method.from_base64url_str
+    pub const fn to_base64url(&self) -> IdStr<6> {
+        IdStr::new(base64url_encode(&self.0), 6)
+    }
 }
 
 #[inline]
@@ -248,6 +948,52 @@ impl SimpleId32 {
     pub const fn into_volumeid32(self) -> VolumeId32 {
         self.0
     }
+
+    /// Tests if `s` is this [`SimpleId32`]'s encoded form, ignoring ASCII case.
+    ///
+    /// This doesn't allocate, so it can be used to match a serial against a
+    /// byte buffer in `no_std` code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fat_volume_id::id32::VolumeId32;
+    ///
+    /// let simple = VolumeId32::parse("6ddcf6da").unwrap().simple();
+    /// assert!(simple.eq_ascii(b"6DDCf6da"));
+    /// ```
+    pub const fn eq_ascii(&self, s: &[u8]) -> bool {
+        eq_ignore_ascii_case(&format_simpleid32(self.0.as_bytes(), false), s)
+    }
+
+    /// Writes the [`VolumeId32`] as a lower-case simple string to `buffer`,
+    /// and returns its first and second halves.
+    ///
+    /// This lets UIs show the two halves separately, like `6ddc` and
+    /// `f6da`, without allocating for each one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fat_volume_id::id32::{VolumeId32, fmt::SimpleId32};
+    ///
+    /// let simple = VolumeId32::parse("6ddcf6da").unwrap().simple();
+    /// let mut buf = [0u8; SimpleId32::LENGTH];
+    ///
+    /// assert_eq!(simple.split_halves(&mut buf), ("6ddc", "f6da"));
+    /// ```
+    ///
+    /// [`VolumeId32`]: ../struct.VolumeId32.html
+    pub fn split_halves<'buf>(
+        &self,
+        buffer: &'buf mut [u8; Self::LENGTH],
+    ) -> (&'buf str, &'buf str) {
+        *buffer = format_simpleid32(self.0.as_bytes(), false);
+        let (hi, lo) = buffer.split_at(Self::LENGTH / 2);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { (str::from_utf8_unchecked(hi), str::from_utf8_unchecked(lo)) }
+    }
 }
 
 impl HyphenatedId32 {
@@ -347,6 +1093,23 @@ impl HyphenatedId32 {
     pub const fn into_volumeid32(self) -> VolumeId32 {
         self.0
     }
+
+    /// Tests if `s` is this [`HyphenatedId32`]'s encoded form, ignoring ASCII case.
+    ///
+    /// This doesn't allocate, so it can be used to match a serial against a
+    /// byte buffer in `no_std` code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fat_volume_id::id32::VolumeId32;
+    ///
+    /// let hyphenated = VolumeId32::parse("6ddc-f6da").unwrap().hyphenated();
+    /// assert!(hyphenated.eq_ascii(b"6DDC-f6DA"));
+    /// ```
+    pub const fn eq_ascii(&self, s: &[u8]) -> bool {
+        eq_ignore_ascii_case(&format_hyphenatedid32(self.0.as_bytes(), false), s)
+    }
 }
 
 impl fmt::Display for SimpleId32 {
@@ -418,7 +1181,7 @@ impl FromStr for SimpleId32 {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         crate::id32::parser::parse_simpleid32(s.as_bytes())
             .map(|b| SimpleId32(VolumeId32(b)))
-            .map_err(|invalid| invalid.into_err())
+            .map_err(|_| crate::id32::InvalidVolumeId32(s.as_bytes()).into_err())
     }
 }
 
@@ -494,3 +1257,467 @@ impl FromStr for HyphenatedId32 {
             .map_err(|invalid| invalid.into_err())
     }
 }
+
+impl fmt::Display for Formatted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.1, self.2) {
+            (FormatKind::Simple, false) => fmt::LowerHex::fmt(self.0.as_simple(), f),
+            (FormatKind::Simple, true) => fmt::UpperHex::fmt(self.0.as_simple(), f),
+            (FormatKind::Hyphenated | FormatKind::Auto, false) => {
+                fmt::LowerHex::fmt(self.0.as_hyphenated(), f)
+            }
+            (FormatKind::Hyphenated | FormatKind::Auto, true) => {
+                fmt::UpperHex::fmt(self.0.as_hyphenated(), f)
+            }
+            (FormatKind::Colon, upper) => write_separated(f, self.0.as_bytes(), 1, ':', upper),
+        }
+    }
+}
+
+impl CasedVolumeId32 {
+    /// Get the underlying [`VolumeId32`].
+    pub const fn volumeid32(&self) -> VolumeId32 {
+        self.volumeid32
+    }
+
+    /// Whether the source string was upper-case.
+    pub const fn is_upper(&self) -> bool {
+        self.upper
+    }
+
+    /// Whether the source string was hyphenated.
+    pub const fn is_hyphenated(&self) -> bool {
+        self.hyphenated
+    }
+}
+
+impl From<CasedVolumeId32> for VolumeId32 {
+    #[inline]
+    fn from(f: CasedVolumeId32) -> Self {
+        f.volumeid32
+    }
+}
+
+impl fmt::Display for CasedVolumeId32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.hyphenated {
+            FormatKind::Hyphenated
+        } else {
+            FormatKind::Simple
+        };
+        fmt::Display::fmt(&self.volumeid32.display_as(kind, self.upper), f)
+    }
+}
+
+impl fmt::Display for Octets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, octet) in self.0.as_bytes().iter().enumerate() {
+            if i > 0 {
+                fmt::Write::write_char(f, '.')?;
+            }
+            write!(f, "{}", octet)?;
+        }
+        return Ok(());
+    }
+}
+
+impl From<Octets> for VolumeId32 {
+    #[inline]
+    fn from(f: Octets) -> Self {
+        f.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn test_display_as() {
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Simple, false)),
+            "6ddcf6da"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Simple, true)),
+            "6DDCF6DA"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Hyphenated, false)),
+            "6ddc-f6da"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Hyphenated, true)),
+            "6DDC-F6DA"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Colon, false)),
+            "6d:dc:f6:da"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Colon, true)),
+            "6D:DC:F6:DA"
+        );
+        assert_eq!(
+            format!("{:>13}", v.display_as(FormatKind::Colon, false)),
+            "  6d:dc:f6:da"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Auto, false)),
+            "6ddc-f6da"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Auto, true)),
+            "6DDC-F6DA"
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(format!("{:?}", v), "VolumeId32(a1a2a3a4)");
+        assert_eq!(format!("{}", v), "a1a2a3a4");
+        assert_eq!(format!("{:?}", VolumeId32::nil()), "VolumeId32(00000000)");
+    }
+
+    #[test]
+    fn test_id_str() {
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+        let simple = v.simple_str();
+
+        assert_eq!(&*simple, "6ddcf6da");
+        assert_eq!(simple, "6ddcf6da");
+        assert_eq!(simple.len(), SimpleId32::LENGTH);
+        assert_eq!(format!("{}", simple), format!("{}", v));
+        assert_eq!(format!("{:?}", simple), "\"6ddcf6da\"");
+
+        let hyphenated = v.hyphenated_str();
+        assert_eq!(hyphenated.as_str(), "6ddc-f6da");
+        assert_eq!(hyphenated.to_uppercase(), "6DDC-F6DA");
+    }
+
+    #[test]
+    fn test_cased_round_trip() {
+        for input in ["6DDC-F6DA", "6ddc-f6da", "6DDCF6DA", "6ddcf6da"] {
+            let cased = VolumeId32::parse_cased(input).unwrap();
+
+            assert_eq!(format!("{}", cased), input);
+            assert_eq!(cased.volumeid32(), VolumeId32::parse(input).unwrap());
+        }
+
+        let cased = VolumeId32::parse_cased("6DDC-F6DA").unwrap();
+        assert!(cased.is_upper());
+        assert!(cased.is_hyphenated());
+    }
+
+    #[test]
+    fn test_simpleid32_eq_ascii() {
+        let simple = VolumeId32::parse("6ddcf6da").unwrap().simple();
+
+        assert!(simple.eq_ascii(b"6ddcf6da"));
+        assert!(simple.eq_ascii(b"6DDCF6DA"));
+        assert!(simple.eq_ascii(b"6DDCf6da"));
+        assert!(!simple.eq_ascii(b"6ddc-f6da"));
+        assert!(!simple.eq_ascii(b"6ddcf6d"));
+        assert!(!simple.eq_ascii(b"6ddcf6d0"));
+    }
+
+    #[test]
+    fn test_hyphenatedid32_eq_ascii() {
+        let hyphenated = VolumeId32::parse("6ddc-f6da").unwrap().hyphenated();
+
+        assert!(hyphenated.eq_ascii(b"6ddc-f6da"));
+        assert!(hyphenated.eq_ascii(b"6DDC-F6DA"));
+        assert!(hyphenated.eq_ascii(b"6DDC-f6DA"));
+        assert!(!hyphenated.eq_ascii(b"6ddcf6da"));
+        assert!(!hyphenated.eq_ascii(b"6ddc-f6d"));
+        assert!(!hyphenated.eq_ascii(b"6ddc-f6d0"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_normalize_cow() {
+        use crate::alloc::borrow::Cow;
+
+        assert!(matches!(
+            VolumeId32::normalize_cow("6ddcf6da"),
+            Ok(Cow::Borrowed("6ddcf6da"))
+        ));
+
+        let owned = VolumeId32::normalize_cow("6DDCF6DA").unwrap();
+        assert!(matches!(owned, Cow::Owned(_)));
+        assert_eq!(owned, "6ddcf6da");
+
+        let owned = VolumeId32::normalize_cow("6ddc-f6da").unwrap();
+        assert!(matches!(owned, Cow::Owned(_)));
+        assert_eq!(owned, "6ddcf6da");
+
+        assert!(VolumeId32::normalize_cow("6ddcf6dg").is_err());
+    }
+
+    #[test]
+    fn test_split_halves() {
+        let simple = VolumeId32::parse("6DDCF6DA").unwrap().simple();
+        let mut buf = [0u8; SimpleId32::LENGTH];
+
+        let (hi, lo) = simple.split_halves(&mut buf);
+        assert_eq!(hi, &"6ddcf6da"[..4]);
+        assert_eq!(lo, &"6ddcf6da"[4..]);
+        assert_eq!(&buf, b"6ddcf6da");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_uuid_string() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(v.to_uuid_string(), "00000000-0000-0000-0000-0000a1a2a3a4");
+        assert_eq!(VolumeId32::from_uuid_str(&v.to_uuid_string()), Ok(v));
+        assert_eq!(
+            VolumeId32::from_uuid_str(&VolumeId32::max().to_uuid_string()),
+            Ok(VolumeId32::max())
+        );
+    }
+
+    #[test]
+    fn test_dotted() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(format!("{}", v.dotted()), "161.162.163.164");
+        assert_eq!(format!("{}", VolumeId32::nil().dotted()), "0.0.0.0");
+        assert_eq!(VolumeId32::from(v.dotted()), v);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_filename() {
+        let v = VolumeId32::parse("A1A2A3A4").unwrap();
+
+        assert_eq!(v.to_filename("fat", "img"), "fat_a1a2a3a4.img");
+        assert_eq!(v.to_filename("usb-disk", ".img"), "usb-disk_a1a2a3a4.img");
+        assert_eq!(
+            v.to_filename("../my disk", "i/m g"),
+            "___my_disk_a1a2a3a4.i_m_g"
+        );
+        assert_eq!(v.to_filename("", ""), "a1a2a3a4");
+    }
+
+    #[test]
+    fn test_to_windows_decimal() {
+        assert_eq!(VolumeId32::nil().to_windows_decimal(), "0");
+        assert_eq!(VolumeId32::max().to_windows_decimal(), "4294967295");
+        assert_eq!(
+            VolumeId32::from_u32(0x6ddcf6da).to_windows_decimal(),
+            "1843197658"
+        );
+    }
+
+    #[test]
+    fn test_encode_bits() {
+        let mut buf = [0u8; 32];
+
+        assert_eq!(
+            VolumeId32::nil().encode_bits(&mut buf),
+            "0".repeat(32).as_str()
+        );
+        assert_eq!(
+            VolumeId32::max().encode_bits(&mut buf),
+            "1".repeat(32).as_str()
+        );
+        assert_eq!(
+            VolumeId32::parse("a1a2a3a4").unwrap().encode_bits(&mut buf),
+            "10100001101000101010001110100100"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_bit_string() {
+        let volumeid32 = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(
+            volumeid32.to_bit_string(),
+            "10100001101000101010001110100100"
+        );
+        assert_eq!(volumeid32.to_bit_string().len(), 32);
+    }
+
+    #[test]
+    fn test_encode_upper() {
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+        let mut buf = [0u8; 9];
+
+        assert_eq!(v.encode_upper(&mut buf), "6DDCF6DA");
+        assert_eq!(
+            v.encode_upper(&mut [0u8; 8]),
+            v.simple().encode_upper(&mut [0u8; 8])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_upper_small_buffer() {
+        VolumeId32::nil().encode_upper(&mut [0u8; 7]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_uppercase_string() {
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+
+        assert_eq!(v.to_uppercase_string(), "6DDCF6DA");
+        assert_eq!(v.to_uppercase_string(), format!("{:X}", v));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_wordcode() {
+        let wordlist = ["zero", "one", "two", "three"];
+
+        for v in [
+            VolumeId32::nil(),
+            VolumeId32::max(),
+            VolumeId32::from_u32(0x6ddcf6da),
+        ] {
+            let words = v.to_wordcode(&wordlist).unwrap();
+            assert_eq!(words.len(), 16);
+            assert_eq!(VolumeId32::from_wordcode(&words, &wordlist), Ok(v));
+        }
+
+        let words = VolumeId32::from_u32(0x6ddcf6da)
+            .to_wordcode(&wordlist[..2])
+            .unwrap();
+        assert_eq!(words.len(), 32);
+        assert_eq!(words[..4], ["zero", "one", "one", "zero"]);
+
+        // 3 bits per word needs 11 words, with one bit left over.
+        let wordlist = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        let words = VolumeId32::max().to_wordcode(&wordlist).unwrap();
+        assert_eq!(words.len(), 11);
+        assert_eq!(words[0], "d");
+        assert_eq!(
+            VolumeId32::from_wordcode(&words, &wordlist),
+            Ok(VolumeId32::max())
+        );
+
+        assert_eq!(
+            VolumeId32::nil().to_wordcode(&wordlist[..3]),
+            Err(Error(ErrorKind::WordlistLength { len: 3 }))
+        );
+        assert_eq!(
+            VolumeId32::nil().to_wordcode(&wordlist[..1]),
+            Err(Error(ErrorKind::WordlistLength { len: 1 }))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dump() {
+        let v = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+
+        assert_eq!(
+            v.dump(),
+            (String::from("a1a2a3a4"), [0xa1, 0xa2, 0xa3, 0xa4])
+        );
+        assert_eq!(v.dump().0, format!("{}", v));
+        assert_eq!(VolumeId32::parse(&v.dump().0), Ok(v));
+    }
+
+    #[test]
+    fn test_to_qr_token() {
+        const QR_ALPHANUMERIC: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+        for v in [
+            VolumeId32::nil(),
+            VolumeId32::max(),
+            VolumeId32::parse("6ddcf6da").unwrap(),
+        ] {
+            let token = v.to_qr_token();
+
+            assert_eq!(token.len(), 8);
+            assert!(token.chars().all(|c| QR_ALPHANUMERIC.contains(c)));
+            assert_eq!(VolumeId32::from_qr_token(&token), Ok(v));
+        }
+    }
+
+    #[test]
+    fn test_to_base64url() {
+        assert_eq!(
+            VolumeId32::parse("a1a2a3a4").unwrap().to_base64url(),
+            "oaKjpA"
+        );
+        assert_eq!(VolumeId32::nil().to_base64url(), "AAAAAA");
+        assert_eq!(VolumeId32::max().to_base64url(), "_____w");
+
+        for v in [
+            VolumeId32::nil(),
+            VolumeId32::max(),
+            VolumeId32::parse("a1a2a3a4").unwrap(),
+            VolumeId32::from_label("base64url"),
+        ] {
+            assert_eq!(VolumeId32::from_base64url_str(&v.to_base64url()), Ok(v));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_blkid_string() {
+        let v = VolumeId32::from_u32(0xa1a2a3a4);
+
+        assert_eq!(v.to_blkid_string(), "UUID=\"A1A2-A3A4\"");
+        assert_eq!(VolumeId32::parse_blkid(&v.to_blkid_string()), Ok(v));
+        assert_eq!(VolumeId32::nil().to_blkid_string(), "UUID=\"0000-0000\"");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_write_grouped() {
+        let v = VolumeId32::parse("6ddcf6da").unwrap();
+
+        let mut s = String::new();
+        v.write_grouped(&mut s, 2, ':', false).unwrap();
+        assert_eq!(s, "6d:dc:f6:da");
+
+        let mut s = String::new();
+        v.write_grouped(&mut s, 4, '-', true).unwrap();
+        assert_eq!(s, "6DDC-F6DA");
+
+        for group in [0, 8, 9] {
+            let mut s = String::new();
+            v.write_grouped(&mut s, group, '-', false).unwrap();
+            assert_eq!(s, "6ddcf6da");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_literals() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+        assert_eq!(v.to_c_literal(), "0xa4a3a2a1");
+        assert_eq!(v.to_rust_bytes_literal(), "[0xa1, 0xa2, 0xa3, 0xa4]");
+
+        assert_eq!(VolumeId32::nil().to_c_literal(), "0x00000000");
+        assert_eq!(
+            VolumeId32::nil().to_rust_bytes_literal(),
+            "[0x00, 0x00, 0x00, 0x00]"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_stored_hex_string() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(v.to_stored_hex_string(), "a4a3a2a1");
+        assert_ne!(v.to_stored_hex_string(), v.to_string());
+        assert_eq!(v.to_stored_hex_string(), format!("{:08x}", v.as_u32()));
+
+        // A palindromic VolumeId32 reads the same either way.
+        let v = VolumeId32::from_bytes([0xab; 4]);
+        assert_eq!(v.to_stored_hex_string(), v.to_string());
+    }
+}
@@ -11,20 +11,29 @@
 // except according to those terms.
 
 use crate::{
-    common::{HEX_TABLE, SHL4_TABLE},
+    common::{
+        FormatKind, HEX_TABLE, HexError, SHL4_TABLE, base64url_decode, hexdump_bytes,
+        strip_wrapper, try_parse_hex_detailed,
+    },
     id32::{
         VolumeId32,
-        error::{Error, InvalidVolumeId32},
-        fmt::{HyphenatedId32, SimpleId32},
+        error::{Error, ErrorKind, InvalidVolumeId32},
+        fmt::{
+            BLKID_FIELD, CasedVolumeId32, HyphenatedId32, LOG_TOKEN_PREFIX, SimpleId32,
+            wordcode_bits,
+        },
     },
-    std::str::FromStr,
+    std::{ffi::CStr, str::FromStr},
 };
 
+#[cfg(feature = "alloc")]
+use crate::common::CharClass;
+
 impl FromStr for VolumeId32 {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::try_parse(s).map_err(|invaild| invaild.into_err())
+        Self::parse(s)
     }
 }
 
@@ -32,7 +41,7 @@ impl TryFrom<&'_ str> for VolumeId32 {
     type Error = Error;
 
     fn try_from(s: &'_ str) -> Result<Self, Self::Error> {
-        Self::try_parse(s).map_err(|invaild| invaild.into_err())
+        Self::parse(s)
     }
 }
 
@@ -41,7 +50,93 @@ impl TryFrom<crate::alloc::string::String> for VolumeId32 {
     type Error = Error;
 
     fn try_from(s: crate::alloc::string::String) -> Result<Self, Self::Error> {
-        Self::try_parse(s.as_ref()).map_err(|invaild| invaild.into_err())
+        Self::parse(&s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl VolumeId32 {
+    /// Parses a [`VolumeId32`] from an [`OsStr`] of hexadecimal digits, such
+    /// as a command line argument.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't valid UTF-8, or if
+    /// it can't be parsed as by [`parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// # use std::ffi::OsStr;
+    /// let volumeid32 = VolumeId32::parse_os(OsStr::new("49aa648a"))
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "49aa648a");
+    /// ```
+    /// [`OsStr`]: std::ffi::OsStr
+    /// [`parse`]: #method.parse
+    pub fn parse_os(input: &std::ffi::OsStr) -> Result<Self, Error> {
+        match input.to_str() {
+            Some(s) => Self::parse(s),
+            None => Err(Error(ErrorKind::ParseInvalidUTF8)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl VolumeId32 {
+    /// Classifies each character of `input` as a hex digit, a separator or
+    /// invalid.
+    ///
+    /// Each character is classified on its own, so this is useful for
+    /// highlighting invalid characters in an editor. Problems with the length
+    /// or the position of separators are reported by [`parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::{CharClass, id32::VolumeId32};
+    /// let classes = VolumeId32::classify_chars("6d-x");
+    ///
+    /// assert_eq!(
+    ///     classes,
+    ///     [
+    ///         CharClass::HexDigit,
+    ///         CharClass::HexDigit,
+    ///         CharClass::Separator,
+    ///         CharClass::Invalid,
+    ///     ]
+    /// );
+    /// ```
+    /// [`parse`]: #method.parse
+    pub fn classify_chars(input: &str) -> crate::alloc::vec::Vec<CharClass> {
+        input
+            .chars()
+            .map(|c| match c {
+                c if c.is_ascii_hexdigit() => CharClass::HexDigit,
+                '-' => CharClass::Separator,
+                _ => CharClass::Invalid,
+            })
+            .collect()
+    }
+
+    /// Parses every string in `inputs`, collecting the results into a `Vec`.
+    ///
+    /// This short-circuits on the first error, which is the same detailed
+    /// error that [`parse`] returns for that string. It's equivalent to
+    /// collecting `inputs.iter().map(|s| s.parse())` into a `Result`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeids = VolumeId32::collect_volume_ids(&["6ddcf6da", "a1a2-a3a4"])
+    ///     .expect("Failed Parsing Strings");
+    ///
+    /// assert_eq!(volumeids.len(), 2);
+    /// assert!(VolumeId32::collect_volume_ids(&["6ddcf6da", "xyz"]).is_err());
+    /// ```
+    /// [`parse`]: #method.parse
+    pub fn collect_volume_ids(inputs: &[&str]) -> Result<crate::alloc::vec::Vec<Self>, Error> {
+        inputs.iter().map(|s| Self::parse(s)).collect()
     }
 }
 
@@ -105,7 +200,7 @@ impl VolumeId32 {
         match (s.len(), s) {
             (8, s) => match parse_simpleid32(s) {
                 Ok(bytes) => Ok(VolumeId32::from_bytes(bytes)),
-                Err(e) => Err(e),
+                Err(_) => Err(InvalidVolumeId32(s)),
             },
             (9, s) => match parse_hyphenatedid32(s) {
                 Ok(bytes) => Ok(VolumeId32::from_bytes(bytes)),
@@ -114,230 +209,1905 @@ impl VolumeId32 {
             _ => Err(InvalidVolumeId32(s)),
         }
     }
-}
-
-#[inline]
-pub(crate) const fn parse_simpleid32(s: &'_ [u8]) -> Result<[u8; 4], InvalidVolumeId32<'_>> {
-    if s.len() != SimpleId32::LENGTH {
-        return Err(InvalidVolumeId32(s));
-    }
 
-    let mut buf = [0u8; 4];
-
-    let mut i = 0;
+    /// Parses a [`VolumeId32`] from a string slice of hexadecimal digits,
+    /// accepting any byte in `seps` as the separator of the hyphenated format.
+    ///
+    /// This is useful for inputs where `-` has been substituted, for example
+    /// `a1a2_a3a4` in a filename. [`parse`] behaves like this method with
+    /// `seps` set to `b"-"`. A separator anywhere other than between the two
+    /// groups is rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse_sep("49aa_648a", b"-_")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32, VolumeId32::parse("49aa-648a").unwrap());
+    /// ```
+    /// [`parse`]: #method.parse
+    pub fn parse_sep(input: &str, seps: &[u8]) -> Result<Self, Error> {
+        let s = input.as_bytes();
+        let parsed = match s.len() {
+            8 => parse_simpleid32(s).map_err(|_| InvalidVolumeId32(s)),
+            9 => parse_separatedid32(s, seps),
+            _ => Err(InvalidVolumeId32(s)),
+        };
 
-    while i < 4 {
-        // Convert a two-char hex value (like `A8`)
-        // into a byte (like `10101000`)
-        let h1 = HEX_TABLE[s[i * 2] as usize];
-        let h2 = HEX_TABLE[s[i * 2 + 1] as usize];
+        parsed
+            .map(VolumeId32::from_bytes)
+            .map_err(|invalid| invalid.into_err_with_separators(seps))
+    }
 
-        // We use `0xff` as a sentinel value to indicate
-        // an invalid hex character sequence (like the letter `G`)
-        if (h1 | h2) == 0xff {
-            return Err(InvalidVolumeId32(s));
+    /// Parses a [`VolumeId32`] from the base-36 string produced by
+    /// [`to_base36`].
+    ///
+    /// The input must be exactly 7 base-36 digits, upper or lower-case,
+    /// encoding the [`as_u32`] value of the VolumeId32.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input has the wrong length,
+    /// contains a character that isn't a base-36 digit or encodes a value
+    /// larger than `u32::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_base36_str("000000z")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32, VolumeId32::from_u32(35));
+    /// ```
+    /// [`to_base36`]: #method.to_base36
+    /// [`as_u32`]: #method.as_u32
+    pub fn from_base36_str(input: &str) -> Result<Self, Error> {
+        if input.len() != 7 {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: input.len(),
+                expected: 7,
+            }));
         }
 
-        // The upper nibble needs to be shifted into position
-        // to produce the final byte value
-        buf[i] = SHL4_TABLE[h1 as usize] | h2;
-        i += 1;
-    }
+        let mut value: u32 = 0;
+        for (index, character) in input.char_indices() {
+            let digit = character
+                .to_digit(36)
+                .ok_or(Error(ErrorKind::ParseEncodedChar {
+                    character,
+                    index: index + 1,
+                    expected: "[0-9a-zA-Z]",
+                }))?;
 
-    return Ok(buf);
-}
+            value = value
+                .checked_mul(36)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(Error(ErrorKind::ParseOutOfRange))?;
+        }
 
-#[inline]
-pub(crate) const fn parse_hyphenatedid32(s: &'_ [u8]) -> Result<[u8; 4], InvalidVolumeId32<'_>> {
-    if s.len() != HyphenatedId32::LENGTH {
-        return Err(InvalidVolumeId32(s));
+        return Ok(VolumeId32::from_u32(value));
     }
 
-    // We look at two hex-encoded values (4 chars) at a time because
-    // that's the size of the smallest group in a hyphenated VolumeId32.
-    // The indexes we're interested in are:
-    //
-    // volumeid32 : 6ddc-f6da
-    //              |   ||
-    // hyphens    : |   4|
-    // positions  : 0    5
+    /// Parses a [`VolumeId32`] from the 6 characters of unpadded base64url
+    /// produced by [`to_base64url`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input has the wrong length,
+    /// contains a character outside the base64url alphabet, or has bits set
+    /// past the end of the 4 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_base64url_str("oaKjpA")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    /// [`to_base64url`]: #method.to_base64url
+    pub fn from_base64url_str(input: &str) -> Result<Self, Error> {
+        if input.len() != 6 {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: input.len(),
+                expected: 6,
+            }));
+        }
 
-    // First, ensure the hyphen appear in the right places
-    match [s[4]] {
-        [b'-'] => {}
-        _ => return Err(InvalidVolumeId32(s)),
+        match base64url_decode(input.as_bytes()) {
+            Ok(bytes) => Ok(VolumeId32::from_bytes(bytes)),
+            Err(Some(index)) => Err(Error(ErrorKind::ParseEncodedChar {
+                character: input[index..].chars().next().unwrap_or_default(),
+                index: index + 1,
+                expected: "[A-Za-z0-9_-]",
+            })),
+            Err(None) => Err(Error(ErrorKind::ParseOutOfRange)),
+        }
     }
 
-    let positions: [u8; 2] = [0, 5];
+    /// Parses a [`VolumeId32`] from a zero-extended UUID string, like
+    /// `00000000-0000-0000-0000-0000a1a2a3a4`, as produced by
+    /// [`to_uuid_string`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input isn't a hyphenated
+    /// UUID, or if any of its first 12 bytes are non-zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_uuid_str("00000000-0000-0000-0000-0000a1a2a3a4")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    /// [`to_uuid_string`]: #method.to_uuid_string
+    pub fn from_uuid_str(input: &str) -> Result<Self, Error> {
+        if input.len() != 36 {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: input.len(),
+                expected: 36,
+            }));
+        }
 
-    let mut buf: [u8; 4] = [0; 4];
-    let mut j = 0;
+        let mut bytes = [0u8; 16];
+        let mut n = 0;
+        for (index, character) in input.char_indices() {
+            if matches!(index, 8 | 13 | 18 | 23) {
+                if character != '-' {
+                    return Err(Error(ErrorKind::ParseEncodedChar {
+                        character,
+                        index: index + 1,
+                        expected: "`-`",
+                    }));
+                }
+                continue;
+            }
 
-    while j < 2 {
-        let i = positions[j];
+            let digit = character
+                .to_digit(16)
+                .ok_or(Error(ErrorKind::ParseEncodedChar {
+                    character,
+                    index: index + 1,
+                    expected: "[0-9a-fA-F]",
+                }))?;
 
-        // The decoding here is the same as the simple case
-        // We're just dealing with two values instead of one
-        let h1 = HEX_TABLE[s[i as usize] as usize];
-        let h2 = HEX_TABLE[s[(i + 1) as usize] as usize];
-        let h3 = HEX_TABLE[s[(i + 2) as usize] as usize];
-        let h4 = HEX_TABLE[s[(i + 3) as usize] as usize];
+            bytes[n / 2] = (bytes[n / 2] << 4) | digit as u8;
+            n += 1;
+        }
 
-        if (h1 | h2 | h3 | h4) == 0xff {
-            return Err(InvalidVolumeId32(s));
+        if bytes[..12] != [0u8; 12] {
+            return Err(Error(ErrorKind::ParseOutOfRange));
         }
 
-        buf[j * 2] = SHL4_TABLE[h1 as usize] | h2;
-        buf[j * 2 + 1] = SHL4_TABLE[h3 as usize] | h4;
-        j += 1;
+        return Ok(VolumeId32::from_bytes([
+            bytes[12], bytes[13], bytes[14], bytes[15],
+        ]));
     }
 
-    Ok(buf)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::id32::error::ErrorKind;
+    /// Parses a [`VolumeId32`] from an ISO 9660 primary or supplementary
+    /// (Joliet) volume descriptor.
+    ///
+    /// Some mastering tools store a 32-bit id as the first 8 hex digits of the
+    /// volume set identifier, at offset 190 of the descriptor, and this reads
+    /// it from there. `desc` must be the whole 2048 byte descriptor. A Joliet
+    /// descriptor stores the identifier as UCS-2 big-endian, so its first 8
+    /// characters take 16 bytes.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `desc` isn't 2048 bytes long, if
+    /// it doesn't have the standard identifier `CD001`, if its type byte isn't
+    /// 1 (primary) or 2 (supplementary), or if the volume set identifier
+    /// doesn't start with 8 hex digits.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let mut desc = [b' '; 2048];
+    /// desc[0] = 1;
+    /// desc[1..6].copy_from_slice(b"CD001");
+    /// desc[190..198].copy_from_slice(b"6DDCF6DA");
+    ///
+    /// let volumeid32 = VolumeId32::from_iso_volume_descriptor(&desc)
+    ///     .expect("Failed Parsing Descriptor");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "6ddcf6da");
+    /// ```
+    pub fn from_iso_volume_descriptor(desc: &[u8]) -> Result<Self, Error> {
+        const DESCRIPTOR_LENGTH: usize = 2048;
+        const VOLUME_SET_ID_OFFSET: usize = 190;
 
-    #[test]
-    fn test_parse_volumeid32_valid() {
-        let simple = VolumeId32::try_parse("6ddcf6da").unwrap();
-        let hyphenated = VolumeId32::try_parse("6ddc-f6da").unwrap();
+        if desc.len() != DESCRIPTOR_LENGTH {
+            return Err(Error(ErrorKind::DescriptorLength {
+                len: desc.len(),
+                expected: DESCRIPTOR_LENGTH,
+            }));
+        }
 
-        assert_eq!(hyphenated, simple);
+        if &desc[1..6] != b"CD001" {
+            return Err(Error(ErrorKind::DescriptorIdentifier));
+        }
 
-        assert!(VolumeId32::try_parse("00000000").is_ok());
-        assert!(VolumeId32::try_parse("0000-0000").is_ok());
+        let mut field = [0u8; SimpleId32::LENGTH];
+        match desc[0] {
+            1 => field.copy_from_slice(
+                &desc[VOLUME_SET_ID_OFFSET..VOLUME_SET_ID_OFFSET + SimpleId32::LENGTH],
+            ),
+            2 => {
+                let ucs2 =
+                    &desc[VOLUME_SET_ID_OFFSET..VOLUME_SET_ID_OFFSET + 2 * SimpleId32::LENGTH];
 
-        assert!(VolumeId32::try_parse("6ddcf6da").is_ok());
-        assert!(VolumeId32::try_parse("6DDCF6DA").is_ok());
+                for (i, pair) in ucs2.chunks_exact(2).enumerate() {
+                    let unit = u16::from_be_bytes([pair[0], pair[1]]);
+                    field[i] = match u8::try_from(unit) {
+                        Ok(byte) if byte.is_ascii() => byte,
+                        _ => {
+                            return Err(Error(ErrorKind::ParseChar {
+                                character: char::from_u32(unit as u32)
+                                    .unwrap_or(char::REPLACEMENT_CHARACTER),
+                                index: i + 1,
+                            }));
+                        }
+                    };
+                }
+            }
+            found => return Err(Error(ErrorKind::DescriptorType { found })),
+        }
 
-        assert!(VolumeId32::try_parse("6ddc-f6da").is_ok());
-        assert!(VolumeId32::try_parse("6DDC-F6DA").is_ok());
+        parse_simpleid32(&field)
+            .map(VolumeId32::from_bytes)
+            .map_err(|err| InvalidVolumeId32(&field).into_err_with_hex(err))
     }
 
-    #[test]
-    fn test_parse_volumeid32_invalid() {
-        assert_eq!(
-            VolumeId32::parse(""),
-            Err(Error(ErrorKind::ParseSimpleLength { len: 0 }))
-        );
+    /// Parses a [`VolumeId32`] from the decimal serial some localized versions
+    /// of Windows print, as produced by [`to_windows_decimal`].
+    ///
+    /// The decimal is read as the [`as_u32`] value, the same value `vol` shows
+    /// as `HHHH-HHHH`. It is not passed to [`from_u32_be`]: that would give the
+    /// VolumeId32 that displays as the decimal's hex digits, which has its
+    /// bytes in the opposite order to the serial Windows printed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input is empty, contains a
+    /// character other than a decimal digit or is larger than `u32::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_windows_decimal("1843197658")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.as_u32(), 0x6ddcf6da);
+    /// ```
+    /// [`to_windows_decimal`]: #method.to_windows_decimal
+    /// [`as_u32`]: #method.as_u32
+    /// [`from_u32_be`]: #method.from_u32_be
+    pub fn from_windows_decimal(input: &str) -> Result<Self, Error> {
+        if input.is_empty() {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: 0,
+                expected: 1,
+            }));
+        }
 
-        assert_eq!(
-            VolumeId32::parse("!"),
-            Err(Error(ErrorKind::ParseChar {
-                character: '!',
-                index: 1,
-            }))
-        );
+        let mut value: u32 = 0;
+        for (index, character) in input.char_indices() {
+            let digit = character
+                .to_digit(10)
+                .ok_or(Error(ErrorKind::ParseEncodedChar {
+                    character,
+                    index: index + 1,
+                    expected: "[0-9]",
+                }))?;
 
-        assert_eq!(
-            VolumeId32::parse("F91-CEB24"),
-            Err(Error(ErrorKind::ParseGroupLength {
-                group: 0,
-                len: 3,
-                index: 1,
-            }))
-        );
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(Error(ErrorKind::ParseOutOfRange))?;
+        }
 
-        assert_eq!(
-            VolumeId32::parse("F916-4fa"),
-            Err(Error(ErrorKind::ParseGroupLength {
-                group: 1,
-                len: 3,
-                index: 6,
-            }))
-        );
+        return Ok(VolumeId32::from_u32(value));
+    }
 
-        assert_eq!(
-            VolumeId32::parse("QABC-1234"),
-            Err(Error(ErrorKind::ParseChar {
-                character: 'Q',
-                index: 1,
-            }))
-        );
+    /// Parses a [`VolumeId32`] from the `UUID=` field of a line of `blkid`
+    /// output, like `/dev/sdb1: UUID="6DDC-F6DA" TYPE="vfat"`.
+    ///
+    /// The value may be quoted, as in the default output, or unquoted, as in
+    /// `blkid -o export`. Like `vol`, `blkid` shows the [`as_u32`] value, so
+    /// `6DDC-F6DA` is the VolumeId32 stored on disk as `daf6dc6d`. It can be
+    /// produced with [`to_blkid_string`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `line` has no `UUID=` field, if
+    /// a quoted value has no closing quote, or if its value can't be parsed as
+    /// by [`parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let line = r#"/dev/sdb1: LABEL="BOOT" UUID="6DDC-F6DA" TYPE="vfat""#;
+    ///
+    /// let volumeid32 = VolumeId32::parse_blkid(line)
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.as_u32(), 0x6ddcf6da);
+    /// ```
+    /// [`as_u32`]: #method.as_u32
+    /// [`to_blkid_string`]: #method.to_blkid_string
+    /// [`parse`]: #method.parse
+    pub fn parse_blkid(line: &str) -> Result<Self, Error> {
+        let value = line
+            .split_ascii_whitespace()
+            .find_map(|field| field.strip_prefix(BLKID_FIELD))
+            .ok_or(Error(ErrorKind::ParseMissingField { field: BLKID_FIELD }))?;
+        let value = match value.strip_prefix('"') {
+            Some(quoted) => quoted
+                .strip_suffix('"')
+                .ok_or(Error(ErrorKind::ParseWrapper))?,
+            None => value,
+        };
 
-        assert_eq!(
-            VolumeId32::parse("F9-16-8C5E"),
-            Err(Error(ErrorKind::ParseGroupCount { count: 3 }))
-        );
+        Self::parse(value).map(|v| VolumeId32::from_bytes_be(v.into_bytes()))
+    }
 
-        assert_eq!(
-            VolumeId32::parse("F9168C5X"),
-            Err(Error(ErrorKind::ParseChar {
-                character: 'X',
-                index: 8,
-            }))
-        );
+    /// Parses a [`VolumeId32`] from four dotted decimal octets, like an IPv4
+    /// address, as produced by [`to_dotted_decimal`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input doesn't contain exactly
+    /// four octets separated by `.`, or if an octet is empty, contains a
+    /// character other than a decimal digit or is larger than 255.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_dotted_decimal("161.162.163.164")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32, VolumeId32::parse("a1a2a3a4").unwrap());
+    /// ```
+    /// [`to_dotted_decimal`]: #method.to_dotted_decimal
+    pub fn from_dotted_decimal(input: &str) -> Result<Self, Error> {
+        let octets = input.split('.').count();
+        if octets != 4 {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: octets,
+                expected: 4,
+            }));
+        }
 
-        assert_eq!(
-            VolumeId32::parse("{F9168C5"),
-            Err(Error(ErrorKind::ParseChar {
-                character: '{',
-                index: 1,
-            }))
-        );
+        let mut bytes = [0u8; 4];
+        let mut start = 0;
+        for (i, octet) in input.split('.').enumerate() {
+            if let Some((index, character)) =
+                octet.char_indices().find(|(_, c)| !c.is_ascii_digit())
+            {
+                return Err(Error(ErrorKind::ParseEncodedChar {
+                    character,
+                    index: start + index + 1,
+                    expected: "[0-9]",
+                }));
+            }
 
-        assert_eq!(
-            VolumeId32::parse("67e5"),
-            Err(Error(ErrorKind::ParseSimpleLength { len: 4 }))
-        );
+            if octet.is_empty() {
+                // Point at the separator next to the missing octet
+                return Err(Error(ErrorKind::ParseEncodedChar {
+                    character: '.',
+                    index: (start + 1).min(input.len()),
+                    expected: "[0-9]",
+                }));
+            }
 
-        assert_eq!(
-            VolumeId32::parse("123456ABC"),
-            Err(Error(ErrorKind::ParseSimpleLength { len: 9 }))
-        );
+            bytes[i] = octet
+                .parse()
+                .map_err(|_| Error(ErrorKind::ParseOutOfRange))?;
+            start += octet.len() + 1;
+        }
 
-        assert_eq!(
-            VolumeId32::parse("67e55abg"),
-            Err(Error(ErrorKind::ParseChar {
-                character: 'g',
-                index: 8,
-            }))
-        );
+        return Ok(VolumeId32::from_bytes(bytes));
+    }
 
+    /// Parses a [`VolumeId32`] from a nul-terminated C string of hexadecimal
+    /// digits.
+    ///
+    /// Only the bytes before the nul terminator are parsed. This is useful
+    /// for serials read out of C FFI buffers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse_cstr(c"49aa648a")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "49aa648a");
+    /// ```
+    pub fn parse_cstr(input: &CStr) -> Result<Self, Error> {
+        Self::try_parse_ascii(input.to_bytes()).map_err(InvalidVolumeId32::into_err)
+    }
+
+    /// Parses a [`VolumeId32`] from a log token produced by [`to_log_token`],
+    /// like `vid32:a1a2a3a4`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input doesn't start with
+    /// `vid32:` or isn't followed by a simple formatted VolumeId32.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_log_token("vid32:a1a2a3a4")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    /// [`to_log_token`]: #method.to_log_token
+    pub fn from_log_token(input: &str) -> Result<Self, Error> {
+        let hex = input
+            .strip_prefix(LOG_TOKEN_PREFIX)
+            .ok_or(Error(ErrorKind::ParsePrefix {
+                expected: LOG_TOKEN_PREFIX,
+            }))?;
+
+        parse_simpleid32(hex.as_bytes())
+            .map(VolumeId32::from_bytes)
+            .map_err(|err| InvalidVolumeId32(hex.as_bytes()).into_err_with_hex(err))
+    }
+
+    /// Parses a [`VolumeId32`] from a QR code token produced by
+    /// [`to_qr_token`], like `6DDCF6DA`.
+    ///
+    /// Only upper-case hex digits are accepted, since those are the characters
+    /// QR alphanumeric mode can encode.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't 8 characters long
+    /// or contains a character that isn't an upper-case hex digit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_qr_token("6DDCF6DA")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "6ddcf6da");
+    /// assert!(VolumeId32::from_qr_token("6ddcf6da").is_err());
+    /// ```
+    /// [`to_qr_token`]: #method.to_qr_token
+    pub fn from_qr_token(input: &str) -> Result<Self, Error> {
+        if input.len() != SimpleId32::LENGTH {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: input.len(),
+                expected: SimpleId32::LENGTH,
+            }));
+        }
+
+        if let Some((index, character)) = input
+            .char_indices()
+            .find(|&(_, c)| !matches!(c, '0'..='9' | 'A'..='F'))
+        {
+            return Err(Error(ErrorKind::ParseEncodedChar {
+                character,
+                index: index + 1,
+                expected: "[0-9A-F]",
+            }));
+        }
+
+        Self::parse(input)
+    }
+
+    /// Parses a [`VolumeId32`] like [`parse`], but remembers whether the input
+    /// was upper-case and hyphenated so it can be displayed the same way.
+    ///
+    /// Input is treated as upper-case if it contains upper-case hex letters and
+    /// no lower-case ones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let cased = VolumeId32::parse_cased("6DDC-F6DA")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(cased.to_string(), "6DDC-F6DA");
+    /// assert_eq!(cased.volumeid32().to_string(), "6ddcf6da");
+    /// ```
+    /// [`parse`]: #method.parse
+    pub fn parse_cased(input: &str) -> Result<CasedVolumeId32, Error> {
+        let volumeid32 = Self::parse(input)?;
+        let bytes = input.as_bytes();
+
+        Ok(CasedVolumeId32 {
+            volumeid32,
+            upper: bytes.iter().any(u8::is_ascii_uppercase)
+                && !bytes.iter().any(u8::is_ascii_lowercase),
+            hyphenated: bytes.len() == HyphenatedId32::LENGTH,
+        })
+    }
+
+    /// Parses a [`VolumeId32`] from a line of `xxd` or `hexdump -C` output,
+    /// reading the 4 bytes at `byte_offset` from the start of the line.
+    ///
+    /// The leading offset column is skipped, and the hex columns end before
+    /// the text column, even if the text happens to look like hex: at the
+    /// double space for `xxd`, and at the `|` or after 16 bytes for
+    /// `hexdump -C`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the line doesn't have 4 bytes
+    /// at `byte_offset`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let line = "00000040: 0000 2900 0000 2da1 a2a3 a44e 4f20 4e41  ..)...-....NO NA";
+    /// let volumeid32 = VolumeId32::from_hexdump_fragment(line, 7)
+    ///     .expect("Failed Parsing Line");
+    ///
+    /// assert_eq!(volumeid32.as_bytes(), &[0xa1, 0xa2, 0xa3, 0xa4]);
+    /// ```
+    pub fn from_hexdump_fragment(line: &str, byte_offset: usize) -> Result<Self, Error> {
+        hexdump_bytes::<4>(line, byte_offset)
+            .map(VolumeId32::from_bytes)
+            .map_err(|len| {
+                Error(ErrorKind::HexdumpLength {
+                    len,
+                    expected: byte_offset.saturating_add(4),
+                })
+            })
+    }
+
+    /// Parses a [`VolumeId32`] like [`parse`], after stripping a single
+    /// matching pair of `{}`, `[]`, `()`, `"` or `'` from around the input.
+    ///
+    /// This accepts serials copied from JSON or debug output. Input without a
+    /// wrapper is parsed as-is.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the wrapper is mismatched or
+    /// nested, or if the unwrapped input can't be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse_unwrapped("{a1a2a3a4}")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    /// [`parse`]: #method.parse
+    pub fn parse_unwrapped(input: &str) -> Result<Self, Error> {
+        match strip_wrapper(input) {
+            Some(inner) => Self::parse(inner),
+            None => Err(Error(ErrorKind::ParseWrapper)),
+        }
+    }
+
+    /// Parses a VolumeId32 from a `key=value` line of a config file, like
+    /// `serial = A1A2-A3A4`, if its key is `key`.
+    ///
+    /// The line is split at the first `=`. Whitespace around the key and
+    /// the value is ignored, and the value is parsed as by [`parse`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `line` has no `=`, if its key
+    /// isn't `key`, or if its value can't be parsed as by [`parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse_kv("serial = A1A2-A3A4", "serial")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    /// [`parse`]: #method.parse
+    pub fn parse_kv(line: &str, key: &str) -> Result<Self, Error> {
+        let (k, value) = line
+            .split_once('=')
+            .ok_or(Error(ErrorKind::ParseMissingDelimiter { delimiter: '=' }))?;
+
+        if k.trim() != key {
+            return Err(Error(ErrorKind::ParseKey));
+        }
+
+        Self::parse(value.trim())
+    }
+
+    /// Parses a VolumeId32 from an array of 8 ASCII hex digits.
+    ///
+    /// This is different from [`from_bytes`], which takes the 4 raw bytes of
+    /// the serial. Use this when the array holds the serial as text, for
+    /// example when read from a fixed-width field of a text format.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any byte isn't a hex digit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::from_ascii_array(b"a1a2a3a4")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "a1a2a3a4");
+    /// ```
+    /// [`from_bytes`]: ../struct.VolumeId32.html#method.from_bytes
+    pub fn from_ascii_array(a: &[u8; 8]) -> Result<Self, Error> {
+        parse_simpleid32(a)
+            .map(VolumeId32::from_bytes)
+            .map_err(|err| InvalidVolumeId32(a).into_err_with_hex(err))
+    }
+
+    /// Parses a [`VolumeId32`] from up to 8 hexadecimal digits, treating
+    /// missing digits as leading zeros.
+    ///
+    /// Some tools print serials as integers and drop their leading zeros, so
+    /// `01a2a3a4` is shown as `1a2a3a4`. Separators aren't accepted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` is empty, longer than
+    /// 8 characters, or contains a character that isn't a hex digit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let volumeid32 = VolumeId32::parse_zero_padded("1a2a3a4")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid32.to_string(), "01a2a3a4");
+    /// ```
+    pub fn parse_zero_padded(input: &str) -> Result<Self, Error> {
+        if input.is_empty() || input.len() > SimpleId32::LENGTH {
+            return Err(Error(ErrorKind::ParseSimpleLength { len: input.len() }));
+        }
+
+        if let Some((index, character)) = input.char_indices().find(|(_, c)| !c.is_ascii_hexdigit())
+        {
+            return Err(Error(ErrorKind::ParseChar {
+                character,
+                index: index + 1,
+            }));
+        }
+
+        let mut padded = [b'0'; SimpleId32::LENGTH];
+        padded[SimpleId32::LENGTH - input.len()..].copy_from_slice(input.as_bytes());
+
+        Self::try_parse_ascii(&padded).map_err(InvalidVolumeId32::into_err)
+    }
+
+    /// Tests if `s` is a valid VolumeId32 string, as accepted by
+    /// [`try_parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// assert!(VolumeId32::is_valid_str("6ddcf6da"));
+    /// assert!(!VolumeId32::is_valid_str("6ddcf6d"));
+    /// ```
+    /// [`try_parse`]: #method.try_parse
+    pub const fn is_valid_str(s: &str) -> bool {
+        Self::try_parse(s).is_ok()
+    }
+
+    /// Returns the format `input` is written in, or `None` if it can't be
+    /// parsed with [`try_parse`].
+    ///
+    /// This lets a tool that accepts both formats echo a VolumeId32 back in
+    /// the format the user typed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::{FormatKind, id32::VolumeId32};
+    /// assert_eq!(VolumeId32::detected_format("6ddcf6da"), Some(FormatKind::Simple));
+    /// assert_eq!(VolumeId32::detected_format("6ddc-f6da"), Some(FormatKind::Hyphenated));
+    /// assert_eq!(VolumeId32::detected_format("6ddcf6d"), None);
+    /// ```
+    /// [`try_parse`]: #method.try_parse
+    pub const fn detected_format(input: &str) -> Option<FormatKind> {
+        match Self::try_parse(input) {
+            Ok(_) if input.len() == HyphenatedId32::LENGTH => Some(FormatKind::Hyphenated),
+            Ok(_) => Some(FormatKind::Simple),
+            Err(_) => None,
+        }
+    }
+
+    /// Parses a [`VolumeId32`] from a word code produced by [`to_wordcode`]
+    /// with the same `wordlist`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the length of `wordlist` isn't a
+    /// power of two of at least 2, if `words` has the wrong number of words,
+    /// if a word isn't in `wordlist`, or if the words encode a value that
+    /// doesn't fit in 32 bits.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id32::VolumeId32;
+    /// let wordlist = ["zero", "one", "two", "three"];
+    /// let words = ["one"; 16];
+    ///
+    /// let volumeid32 = VolumeId32::from_wordcode(&words, &wordlist)
+    ///     .expect("Failed Parsing Words");
+    ///
+    /// assert_eq!(volumeid32.as_u32(), 0x55555555);
+    /// ```
+    /// [`to_wordcode`]: #method.to_wordcode
+    pub fn from_wordcode(words: &[&str], wordlist: &[&str]) -> Result<Self, Error> {
+        let bits = wordcode_bits(wordlist.len())?;
+        let expected = 32u32.div_ceil(bits) as usize;
+        if words.len() != expected {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: words.len(),
+                expected,
+            }));
+        }
+
+        let mut value: u64 = 0;
+        for (i, word) in words.iter().enumerate() {
+            let index = wordlist
+                .iter()
+                .position(|w| w == word)
+                .ok_or(Error(ErrorKind::ParseWord { index: i + 1 }))?;
+            value = (value << bits) | index as u64;
+        }
+
+        let value = u32::try_from(value).map_err(|_| Error(ErrorKind::ParseOutOfRange))?;
+        return Ok(VolumeId32::from_u32(value));
+    }
+}
+
+/// An incremental parser that reads a [`VolumeId32`] one character at a time.
+///
+/// This is for interactive input, where each character can be checked as it
+/// is typed instead of parsing the whole buffer again. Both the simple and
+/// hyphenated formats are accepted.
+///
+/// # Examples
+/// ```
+/// # use fat_volume_id::id32::VolumeId32Parser;
+/// let mut parser = VolumeId32Parser::new();
+///
+/// for c in "6ddc-f6d".chars() {
+///     assert_eq!(parser.push(c), Ok(None));
+/// }
+/// assert!(parser.push('x').is_err());
+///
+/// let volumeid32 = parser.push('a').unwrap().unwrap();
+///
+/// assert_eq!(volumeid32.to_string(), "6ddcf6da");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VolumeId32Parser {
+    bytes: [u8; 4],
+    digits: usize,
+    hyphenated: bool,
+}
+
+impl VolumeId32Parser {
+    /// Creates a parser with no input.
+    pub const fn new() -> Self {
+        VolumeId32Parser {
+            bytes: [0; 4],
+            digits: 0,
+            hyphenated: false,
+        }
+    }
+
+    /// Adds the next character of input, returning the [`VolumeId32`] once
+    /// the last hex digit is added.
+    ///
+    /// A character that can't come next is rejected and not added, so the
+    /// parser is left as it was and the user can try again.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `c` isn't a hex digit, if it is
+    /// a `-` anywhere other than after the fourth hex digit, or if the
+    /// [`VolumeId32`] is already complete. The index in the error is the
+    /// position `c` would have had in the input.
+    pub fn push(&mut self, c: char) -> Result<Option<VolumeId32>, Error> {
+        let index = self.len() + 1;
+
+        if c == '-' {
+            if self.digits != 4 || self.hyphenated {
+                return Err(Error(ErrorKind::ParseSeparatorPosition { index }));
+            }
+            self.hyphenated = true;
+            return Ok(None);
+        }
+
+        let nibble = match c.to_digit(16) {
+            Some(nibble) if self.digits < 8 => nibble as u8,
+            _ => {
+                return Err(Error(ErrorKind::ParseChar {
+                    character: c,
+                    index,
+                }));
+            }
+        };
+
+        self.bytes[self.digits / 2] |= if self.digits.is_multiple_of(2) {
+            nibble << 4
+        } else {
+            nibble
+        };
+        self.digits += 1;
+
+        if self.digits == 8 {
+            return Ok(Some(VolumeId32::from_bytes(self.bytes)));
+        }
+
+        return Ok(None);
+    }
+
+    /// Returns the number of characters added so far.
+    pub const fn len(&self) -> usize {
+        self.digits + self.hyphenated as usize
+    }
+
+    /// Tests if no characters have been added.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all input, so a new [`VolumeId32`] can be parsed.
+    pub fn reset(&mut self) {
+        *self = VolumeId32Parser::new();
+    }
+}
+
+#[inline]
+pub(crate) const fn parse_simpleid32(s: &[u8]) -> Result<[u8; 4], HexError> {
+    try_parse_hex_detailed::<4>(s)
+}
+
+#[inline]
+pub(crate) const fn parse_hyphenatedid32(s: &'_ [u8]) -> Result<[u8; 4], InvalidVolumeId32<'_>> {
+    parse_separatedid32(s, b"-")
+}
+
+#[inline]
+pub(crate) const fn parse_separatedid32<'a>(
+    s: &'a [u8],
+    seps: &[u8],
+) -> Result<[u8; 4], InvalidVolumeId32<'a>> {
+    if s.len() != HyphenatedId32::LENGTH {
+        return Err(InvalidVolumeId32(s));
+    }
+
+    // We look at two hex-encoded values (4 chars) at a time because
+    // that's the size of the smallest group in a hyphenated VolumeId32.
+    // The indexes we're interested in are:
+    //
+    // volumeid32 : 6ddc-f6da
+    //              |   ||
+    // hyphens    : |   4|
+    // positions  : 0    5
+
+    // First, ensure the separator appears in the right place
+    if !is_separator(s[4], seps) {
+        return Err(InvalidVolumeId32(s));
+    }
+
+    let positions: [u8; 2] = [0, 5];
+
+    let mut buf: [u8; 4] = [0; 4];
+    let mut j = 0;
+
+    while j < 2 {
+        let i = positions[j];
+
+        // The decoding here is the same as the simple case
+        // We're just dealing with two values instead of one
+        let h1 = HEX_TABLE[s[i as usize] as usize];
+        let h2 = HEX_TABLE[s[(i + 1) as usize] as usize];
+        let h3 = HEX_TABLE[s[(i + 2) as usize] as usize];
+        let h4 = HEX_TABLE[s[(i + 3) as usize] as usize];
+
+        if (h1 | h2 | h3 | h4) == 0xff {
+            return Err(InvalidVolumeId32(s));
+        }
+
+        buf[j * 2] = SHL4_TABLE[h1 as usize] | h2;
+        buf[j * 2 + 1] = SHL4_TABLE[h3 as usize] | h4;
+        j += 1;
+    }
+
+    Ok(buf)
+}
+
+#[inline]
+pub(crate) const fn is_separator(byte: u8, seps: &[u8]) -> bool {
+    let mut i = 0;
+    while i < seps.len() {
+        if seps[i] == byte {
+            return true;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_volumeid32_valid() {
+        let simple = VolumeId32::try_parse("6ddcf6da").unwrap();
+        let hyphenated = VolumeId32::try_parse("6ddc-f6da").unwrap();
+
+        assert_eq!(hyphenated, simple);
+
+        assert!(VolumeId32::try_parse("00000000").is_ok());
+        assert!(VolumeId32::try_parse("0000-0000").is_ok());
+
+        assert!(VolumeId32::try_parse("6ddcf6da").is_ok());
+        assert!(VolumeId32::try_parse("6DDCF6DA").is_ok());
+
+        assert!(VolumeId32::try_parse("6ddc-f6da").is_ok());
+        assert!(VolumeId32::try_parse("6DDC-F6DA").is_ok());
+    }
+
+    #[test]
+    fn test_parse_volumeid32_invalid() {
+        assert_eq!(
+            VolumeId32::parse(""),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 0 }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("!"),
+            Err(Error(ErrorKind::ParseChar {
+                character: '!',
+                index: 1,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("F91-CEB24"),
+            Err(Error(ErrorKind::ParseSeparatorPosition { index: 4 }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("F916-4fa"),
+            Err(Error(ErrorKind::ParseGroupLength {
+                group: 1,
+                len: 3,
+                index: 6,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("QABC-1234"),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'Q',
+                index: 1,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("F9-16-8C5E"),
+            Err(Error(ErrorKind::ParseGroupCount { count: 3 }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("F9168C5X"),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'X',
+                index: 8,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("{F9168C5"),
+            Err(Error(ErrorKind::ParseChar {
+                character: '{',
+                index: 1,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("67e5"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 4 }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("123456ABC"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 9 }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("67e55abg"),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'g',
+                index: 8,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("67e5%2fb"),
+            Err(Error(ErrorKind::ParseChar {
+                character: '%',
+                index: 5,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("231231212212423424324323477343246663"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 36 }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("67e5504410b1426f9247bb680e5fe0c"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 31 }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("67e550Xb"),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'X',
+                index: 7,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("F916BA-CE"),
+            Err(Error(ErrorKind::ParseSeparatorPosition { index: 7 }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse("\u{bcf3c}"),
+            Err(Error(ErrorKind::ParseChar {
+                character: '\u{bcf3c}',
+                index: 1
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_sep() {
+        let expected = VolumeId32::parse("6ddc-f6da").unwrap();
+
+        assert_eq!(VolumeId32::parse_sep("6ddc-f6da", b"-_"), Ok(expected));
+        assert_eq!(VolumeId32::parse_sep("6ddc_f6da", b"-_"), Ok(expected));
+        assert_eq!(VolumeId32::parse_sep("6ddcf6da", b"-_"), Ok(expected));
+
+        assert_eq!(
+            VolumeId32::parse_sep("6ddc_f6da", b"-"),
+            Err(Error(ErrorKind::ParseChar {
+                character: '_',
+                index: 5,
+            }))
+        );
+
+        assert_eq!(
+            VolumeId32::parse_sep("6dd_cf6da", b"-_"),
+            Err(Error(ErrorKind::ParseSeparatorPosition { index: 4 }))
+        );
+    }
+
+    #[test]
+    fn test_conversions_preserve_detailed_errors() {
+        let expected = Err(Error(ErrorKind::ParseChar {
+            character: 'x',
+            index: 8,
+        }));
+
+        assert_eq!(VolumeId32::try_from("6ddcf6dx"), expected);
+        assert_eq!("6ddcf6dx".parse::<VolumeId32>(), expected);
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            VolumeId32::try_from(crate::alloc::string::String::from("6ddcf6dx")),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_separator_position() {
+        let err = VolumeId32::parse("a1a-2a3a4").unwrap_err();
+
+        assert_eq!(err, Error(ErrorKind::ParseSeparatorPosition { index: 4 }));
+        assert_eq!(
+            std::format!("{}", err),
+            "invalid separator position: unexpected separator at 4"
+        );
+
+        assert_eq!(
+            VolumeId32::parse("a1a2a3a4-"),
+            Err(Error(ErrorKind::ParseSeparatorPosition { index: 9 }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_base36() {
+        for v in [
+            VolumeId32::nil(),
+            VolumeId32::max(),
+            VolumeId32::parse("6ddcf6da").unwrap(),
+        ] {
+            let encoded = v.to_base36();
+            assert_eq!(encoded.len(), 7);
+            assert_eq!(VolumeId32::from_base36_str(&encoded), Ok(v));
+            assert_eq!(VolumeId32::from_base36_str(&encoded.to_uppercase()), Ok(v));
+        }
+
+        assert_eq!(
+            VolumeId32::from_base36_str("zzzzzzz"),
+            Err(Error(ErrorKind::ParseOutOfRange))
+        );
+        assert_eq!(
+            VolumeId32::from_base36_str("00000-1"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '-',
+                index: 6,
+                expected: "[0-9a-zA-Z]",
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_base36_str("z"),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 1,
+                expected: 7,
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_dotted_decimal() {
+        for v in [
+            VolumeId32::nil(),
+            VolumeId32::max(),
+            VolumeId32::parse("6ddcf6da").unwrap(),
+        ] {
+            assert_eq!(
+                VolumeId32::from_dotted_decimal(&v.to_dotted_decimal()),
+                Ok(v)
+            );
+        }
+
+        assert_eq!(
+            VolumeId32::parse("6ddcf6da").unwrap().to_dotted_decimal(),
+            "109.220.246.218"
+        );
+        assert_eq!(
+            VolumeId32::from_dotted_decimal("010.0.0.1"),
+            Ok(VolumeId32::from_bytes([10, 0, 0, 1]))
+        );
+    }
+
+    #[test]
+    fn test_dotted_decimal_invalid() {
+        assert_eq!(
+            VolumeId32::from_dotted_decimal("1.2.256.4"),
+            Err(Error(ErrorKind::ParseOutOfRange))
+        );
+        assert_eq!(
+            VolumeId32::from_dotted_decimal("1.2.3"),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 3,
+                expected: 4,
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_dotted_decimal("1.2.3.4.5"),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 5,
+                expected: 4,
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_dotted_decimal("1.+2.3.4"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '+',
+                index: 3,
+                expected: "[0-9]",
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_dotted_decimal("1..3.4"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '.',
+                index: 3,
+                expected: "[0-9]",
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_dotted_decimal("1.2.3."),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '.',
+                index: 6,
+                expected: "[0-9]",
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_cstr() {
+        assert_eq!(
+            VolumeId32::parse_cstr(c"49aa648a"),
+            VolumeId32::parse("49aa648a")
+        );
+        assert_eq!(
+            VolumeId32::parse_cstr(c"49aa648g"),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'g',
+                index: 8,
+            }))
+        );
+        assert_eq!(
+            VolumeId32::parse_cstr(c"\xff"),
+            Err(Error(ErrorKind::ParseInvalidUTF8))
+        );
+    }
+
+    #[test]
+    fn test_parse_overlong() {
+        let err = VolumeId32::parse("a1a2-a3a4-").unwrap_err();
+
+        assert_eq!(
+            err,
+            Error(ErrorKind::ParseGroupLength {
+                group: 1,
+                len: 5,
+                index: 6
+            })
+        );
+        assert_eq!(
+            std::format!("{}", err),
+            "invalid group length in group 1: expected 4, found 5"
+        );
+
+        assert_eq!(
+            VolumeId32::parse("a1a2-a3a4a5a6"),
+            Err(Error(ErrorKind::ParseGroupLength {
+                group: 1,
+                len: 8,
+                index: 6
+            }))
+        );
+        assert_eq!(
+            VolumeId32::parse("a1a2a3a4a5"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 10 }))
+        );
+    }
+
+    #[test]
+    fn test_log_token() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+        let token = v.to_log_token();
+
+        assert_eq!(token, "vid32:a1a2a3a4");
+        assert_eq!(VolumeId32::from_log_token(&token), Ok(v));
+
+        assert_eq!(
+            VolumeId32::from_log_token("vid64:a1a2a3a4"),
+            Err(Error(ErrorKind::ParsePrefix { expected: "vid32:" }))
+        );
+        assert_eq!(
+            VolumeId32::from_log_token("a1a2a3a4"),
+            Err(Error(ErrorKind::ParsePrefix { expected: "vid32:" }))
+        );
+        assert_eq!(
+            VolumeId32::from_log_token("vid32:a1a2a3a"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 7 }))
+        );
+    }
+
+    #[test]
+    fn test_from_hexdump_fragment() {
+        let expected = VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]);
+
+        assert_eq!(
+            VolumeId32::from_hexdump_fragment(
+                "00000040: 0000 2900 0000 2da1 a2a3 a44e 4f20 4e41  ..)...-....NO NA",
+                7
+            ),
+            Ok(expected)
+        );
+        assert_eq!(
+            VolumeId32::from_hexdump_fragment(
+                "00000040  00 00 29 a1 a2 a3 a4 4e  4f 20 4e 41 4d 45 20 20  |..)....NO NAME  |",
+                3
+            ),
+            Ok(expected)
+        );
+
+        assert_eq!(
+            VolumeId32::from_hexdump_fragment("00000040: 0000 2900", 2),
+            Err(Error(ErrorKind::HexdumpLength {
+                len: 4,
+                expected: 6,
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_hexdump_fragment("", 0),
+            Err(Error(ErrorKind::HexdumpLength {
+                len: 0,
+                expected: 4,
+            }))
+        );
+
+        // The text columns are valid hex, but aren't read as bytes.
+        assert_eq!(
+            VolumeId32::from_hexdump_fragment(
+                "00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef",
+                14
+            ),
+            Err(Error(ErrorKind::HexdumpLength {
+                len: 16,
+                expected: 18,
+            }))
+        );
         assert_eq!(
-            VolumeId32::parse("67e5%2fb"),
+            VolumeId32::from_hexdump_fragment(
+                "00000000  30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66  0123 4567",
+                14
+            ),
+            Err(Error(ErrorKind::HexdumpLength {
+                len: 16,
+                expected: 18,
+            }))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_classify_chars() {
+        use crate::common::CharClass::*;
+
+        assert_eq!(
+            VolumeId32::classify_chars("6dDc-zé_0"),
+            [
+                HexDigit, HexDigit, HexDigit, HexDigit, Separator, Invalid, Invalid, Invalid,
+                HexDigit,
+            ]
+        );
+        assert!(VolumeId32::classify_chars("").is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_collect_volume_ids() {
+        use crate::alloc::vec::Vec;
+
+        let inputs = ["6ddcf6da", "6ddc-f6dg", "a1a2a3a4"];
+
+        let collected: Result<Vec<VolumeId32>, _> =
+            inputs.iter().map(|s| s.parse::<VolumeId32>()).collect();
+        let expected = Err(Error(ErrorKind::ParseChar {
+            character: 'g',
+            index: 9,
+        }));
+
+        assert_eq!(collected, expected);
+        assert_eq!(VolumeId32::collect_volume_ids(&inputs), expected);
+        assert_eq!(
+            VolumeId32::collect_volume_ids(&[inputs[0], inputs[2]]),
+            Ok(Vec::from([
+                VolumeId32::parse(inputs[0]).unwrap(),
+                VolumeId32::parse(inputs[2]).unwrap()
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_from_uuid_str() {
+        assert_eq!(
+            VolumeId32::from_uuid_str("00000000-0000-0000-0000-0000A1A2a3a4"),
+            Ok(VolumeId32::from_bytes([0xa1, 0xa2, 0xa3, 0xa4]))
+        );
+        assert_eq!(
+            VolumeId32::from_uuid_str("00000000-0000-0000-0000-0001a1a2a3a4"),
+            Err(Error(ErrorKind::ParseOutOfRange))
+        );
+        assert_eq!(
+            VolumeId32::from_uuid_str("00000000-0000-0000-0000-0000a1a2a3a"),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 35,
+                expected: 36,
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_uuid_str("00000000-0000-0000-00000-000a1a2a3a4"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '0',
+                index: 24,
+                expected: "`-`",
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_uuid_str("00000000-0000-0000-0000-0000a1a2a3ag"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: 'g',
+                index: 36,
+                expected: "[0-9a-fA-F]",
+            }))
+        );
+    }
+
+    #[test]
+    fn test_from_iso_volume_descriptor() {
+        let mut desc = [b' '; 2048];
+        desc[0] = 1;
+        desc[1..6].copy_from_slice(b"CD001");
+        desc[190..198].copy_from_slice(b"6ddcf6da");
+
+        assert_eq!(
+            VolumeId32::from_iso_volume_descriptor(&desc),
+            Ok(VolumeId32::parse("6ddcf6da").unwrap())
+        );
+        assert_eq!(
+            VolumeId32::from_iso_volume_descriptor(&desc[..2047]),
+            Err(Error(ErrorKind::DescriptorLength {
+                len: 2047,
+                expected: 2048,
+            }))
+        );
+
+        desc[197] = b' ';
+        assert_eq!(
+            VolumeId32::from_iso_volume_descriptor(&desc),
             Err(Error(ErrorKind::ParseChar {
-                character: '%',
-                index: 5,
+                character: ' ',
+                index: 8,
             }))
         );
 
+        desc[0] = 0xff;
         assert_eq!(
-            VolumeId32::parse("231231212212423424324323477343246663"),
-            Err(Error(ErrorKind::ParseSimpleLength { len: 36 }))
+            VolumeId32::from_iso_volume_descriptor(&desc),
+            Err(Error(ErrorKind::DescriptorType { found: 0xff }))
         );
 
+        desc[0] = 1;
+        desc[1..6].copy_from_slice(b"CD002");
         assert_eq!(
-            VolumeId32::parse("67e5504410b1426f9247bb680e5fe0c"),
-            Err(Error(ErrorKind::ParseSimpleLength { len: 31 }))
+            VolumeId32::from_iso_volume_descriptor(&desc),
+            Err(Error(ErrorKind::DescriptorIdentifier))
+        );
+    }
+
+    #[test]
+    fn test_from_iso_volume_descriptor_joliet() {
+        let mut desc = [0u8; 2048];
+        desc[0] = 2;
+        desc[1..6].copy_from_slice(b"CD001");
+        for (i, c) in "6DDCF6DA".encode_utf16().enumerate() {
+            desc[190 + i * 2..192 + i * 2].copy_from_slice(&c.to_be_bytes());
+        }
+
+        assert_eq!(
+            VolumeId32::from_iso_volume_descriptor(&desc),
+            Ok(VolumeId32::parse("6ddcf6da").unwrap())
         );
 
+        desc[192..194].copy_from_slice(&0x00e9u16.to_be_bytes());
         assert_eq!(
-            VolumeId32::parse("67e550Xb"),
+            VolumeId32::from_iso_volume_descriptor(&desc),
             Err(Error(ErrorKind::ParseChar {
-                character: 'X',
-                index: 7,
+                character: 'é',
+                index: 2,
             }))
         );
 
+        desc[192..194].copy_from_slice(b"DD");
         assert_eq!(
-            VolumeId32::parse("F916BA-CE"),
-            Err(Error(ErrorKind::ParseGroupLength {
-                group: 0,
-                len: 6,
+            VolumeId32::from_iso_volume_descriptor(&desc),
+            Err(Error(ErrorKind::ParseChar {
+                character: '䑄',
+                index: 2,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_unwrapped() {
+        let expected = Ok(VolumeId32::parse("a1a2a3a4").unwrap());
+
+        for input in [
+            "a1a2a3a4",
+            "{a1a2a3a4}",
+            "[a1a2a3a4]",
+            "(a1a2a3a4)",
+            "\"a1a2a3a4\"",
+            "'a1a2a3a4'",
+        ] {
+            assert_eq!(VolumeId32::parse_unwrapped(input), expected);
+        }
+
+        for input in [
+            "{a1a2a3a4]",
+            "{a1a2a3a4",
+            "a1a2a3a4}",
+            "[[a1a2a3a4]]",
+            "{\"a1a2a3a4\"}",
+            "{",
+        ] {
+            assert_eq!(
+                VolumeId32::parse_unwrapped(input),
+                Err(Error(ErrorKind::ParseWrapper))
+            );
+        }
+
+        assert_eq!(VolumeId32::parse_unwrapped("{}"), VolumeId32::parse(""));
+    }
+
+    #[test]
+    fn test_is_valid_str() {
+        const _: () = assert!(VolumeId32::is_valid_str("6ddcf6da"));
+
+        assert!(VolumeId32::is_valid_str("6DDC-F6DA"));
+        assert!(!VolumeId32::is_valid_str("6ddcf6d"));
+        assert!(!VolumeId32::is_valid_str("6ddc_f6da"));
+        assert!(!VolumeId32::is_valid_str("6ddcf6dg"));
+        assert!(!VolumeId32::is_valid_str(""));
+    }
+
+    #[test]
+    fn test_windows_decimal() {
+        for value in [0, 1, 0x6ddcf6da, u32::MAX] {
+            let v = VolumeId32::from_u32(value);
+
+            assert_eq!(
+                VolumeId32::from_windows_decimal(&v.to_windows_decimal()),
+                Ok(v)
+            );
+        }
+
+        // 1843197658 is 0x6DDCF6DA, which `vol` shows as 6DDC-F6DA
+        assert_eq!(
+            VolumeId32::from_windows_decimal("1843197658"),
+            VolumeId32::parse_blkid("UUID=\"6DDC-F6DA\"")
+        );
+
+        assert_eq!(
+            VolumeId32::from_windows_decimal("4294967296"),
+            Err(Error(ErrorKind::ParseOutOfRange))
+        );
+        assert_eq!(
+            VolumeId32::from_windows_decimal("+1"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '+',
                 index: 1,
+                expected: "[0-9]",
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_windows_decimal(""),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 0,
+                expected: 1,
             }))
         );
+    }
 
+    #[test]
+    fn test_detected_format() {
         assert_eq!(
-            VolumeId32::parse("\u{bcf3c}"),
+            VolumeId32::detected_format("6DDCF6DA"),
+            Some(FormatKind::Simple)
+        );
+        assert_eq!(
+            VolumeId32::detected_format("6ddc-f6da"),
+            Some(FormatKind::Hyphenated)
+        );
+        assert_eq!(VolumeId32::detected_format("6ddc_f6da"), None);
+        assert_eq!(VolumeId32::detected_format("6ddc-f6dg"), None);
+        assert_eq!(VolumeId32::detected_format("6d:dc:f6:da"), None);
+        assert_eq!(VolumeId32::detected_format(""), None);
+    }
+
+    #[test]
+    fn test_from_ascii_array() {
+        let ascii = *b"a1a2a3a4";
+
+        assert_eq!(
+            VolumeId32::from_ascii_array(&ascii).unwrap(),
+            VolumeId32::parse("a1a2a3a4").unwrap()
+        );
+        assert_ne!(
+            VolumeId32::from_ascii_array(&ascii).unwrap().as_bytes()[..],
+            ascii[..4]
+        );
+        assert_eq!(
+            VolumeId32::from_ascii_array(&ascii).unwrap().as_bytes(),
+            &[0xa1, 0xa2, 0xa3, 0xa4]
+        );
+
+        let mut invalid = ascii;
+        invalid[1] = b'g';
+        assert_eq!(
+            VolumeId32::from_ascii_array(&invalid),
             Err(Error(ErrorKind::ParseChar {
-                character: '\u{bcf3c}',
-                index: 1
+                character: 'g',
+                index: 2,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_simpleid32_detailed() {
+        assert_eq!(
+            VolumeId32::from_log_token("vid32:a1é"),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'é',
+                index: 3,
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_log_token("vid32:a1a2"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 4 }))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_os() {
+        use std::ffi::OsStr;
+
+        assert_eq!(
+            VolumeId32::parse_os(OsStr::new("49aa648a")),
+            VolumeId32::parse("49aa648a")
+        );
+        assert_eq!(
+            VolumeId32::parse_os(OsStr::new("49aa648")),
+            VolumeId32::parse("49aa648")
+        );
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn test_parse_os_invalid_utf8() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        assert_eq!(
+            VolumeId32::parse_os(OsStr::from_bytes(b"49aa648\xff")),
+            Err(Error(ErrorKind::ParseInvalidUTF8))
+        );
+    }
+
+    #[test]
+    fn test_from_wordcode_errors() {
+        let wordlist = ["zero", "one", "two", "three"];
+
+        assert_eq!(
+            VolumeId32::from_wordcode(&["one"; 15], &wordlist),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 15,
+                expected: 16,
+            }))
+        );
+
+        let mut words = ["one"; 16];
+        words[3] = "four";
+        assert_eq!(
+            VolumeId32::from_wordcode(&words, &wordlist),
+            Err(Error(ErrorKind::ParseWord { index: 4 }))
+        );
+
+        assert_eq!(
+            VolumeId32::from_wordcode(&words, &wordlist[..3]),
+            Err(Error(ErrorKind::WordlistLength { len: 3 }))
+        );
+
+        // 3 bits per word encodes 33 bits, so the leading word must be below 4.
+        let wordlist = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        let mut words = ["a"; 11];
+        words[0] = "e";
+        assert_eq!(
+            VolumeId32::from_wordcode(&words, &wordlist),
+            Err(Error(ErrorKind::ParseOutOfRange))
+        );
+    }
+
+    #[test]
+    fn test_parse_zero_padded() {
+        assert_eq!(
+            VolumeId32::parse_zero_padded("1a2a3a4"),
+            VolumeId32::parse("01a2a3a4")
+        );
+        assert_eq!(
+            VolumeId32::parse_zero_padded("01a2a3a4"),
+            VolumeId32::parse("01a2a3a4")
+        );
+        assert_eq!(VolumeId32::parse_zero_padded("0"), Ok(VolumeId32::nil()));
+        assert_eq!(
+            VolumeId32::parse_zero_padded("a"),
+            VolumeId32::parse("0000000a")
+        );
+
+        assert_eq!(
+            VolumeId32::parse_zero_padded("01a2a3a40"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 9 }))
+        );
+        assert_eq!(
+            VolumeId32::parse_zero_padded(""),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 0 }))
+        );
+        assert_eq!(
+            VolumeId32::parse_zero_padded("1a2-a4"),
+            Err(Error(ErrorKind::ParseChar {
+                character: '-',
+                index: 4,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_from_qr_token() {
+        assert_eq!(
+            VolumeId32::from_qr_token("6DDCF6DA"),
+            VolumeId32::parse("6ddcf6da")
+        );
+
+        assert_eq!(
+            VolumeId32::from_qr_token("6Ddcf6da"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: 'd',
+                index: 3,
+                expected: "[0-9A-F]",
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_qr_token("6DDCF6D"),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 7,
+                expected: 8,
+            }))
+        );
+        assert!(VolumeId32::from_qr_token("6DDC-6DA").is_err());
+    }
+
+    #[test]
+    fn test_from_base64url_str() {
+        assert_eq!(
+            VolumeId32::from_base64url_str("oaKjpA"),
+            VolumeId32::parse("a1a2a3a4")
+        );
+        assert_eq!(
+            VolumeId32::from_base64url_str("_____w"),
+            Ok(VolumeId32::max())
+        );
+
+        assert_eq!(
+            VolumeId32::from_base64url_str("oa+jpA"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '+',
+                index: 3,
+                expected: "[A-Za-z0-9_-]",
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_base64url_str("oaKjpA="),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 7,
+                expected: 6,
+            }))
+        );
+        assert_eq!(
+            VolumeId32::from_base64url_str("oaKjpB"),
+            Err(Error(ErrorKind::ParseOutOfRange))
+        );
+    }
+
+    #[test]
+    fn test_parse_blkid() {
+        let expected = Ok(VolumeId32::from_u32(0xa1a2a3a4));
+
+        assert_eq!(
+            VolumeId32::parse_blkid(
+                r#"/dev/sdb1: LABEL_FATBOOT="EFI" LABEL="EFI" UUID="A1A2-A3A4" BLOCK_SIZE="512" TYPE="vfat" PARTUUID="0fc63daf-01""#
+            ),
+            expected
+        );
+        assert_eq!(VolumeId32::parse_blkid("UUID=A1A2-A3A4"), expected);
+        assert_eq!(
+            VolumeId32::parse_blkid(r#"/dev/sdc1: UUID="a1a2-a3a4" TYPE="exfat""#),
+            expected
+        );
+
+        assert_eq!(
+            VolumeId32::parse_blkid(r#"/dev/sdb2: PARTUUID="0fc63daf-02""#),
+            Err(Error(ErrorKind::ParseMissingField { field: "UUID=" }))
+        );
+        assert_eq!(
+            VolumeId32::parse_blkid(""),
+            Err(Error(ErrorKind::ParseMissingField { field: "UUID=" }))
+        );
+        assert!(VolumeId32::parse_blkid(r#"/dev/sdb1: UUID="A1A2-A3A""#).is_err());
+        assert_eq!(
+            VolumeId32::parse_blkid(r#"/dev/sdb1: UUID="A1A2-A3A4 TYPE="vfat""#),
+            Err(Error(ErrorKind::ParseWrapper))
+        );
+        assert_eq!(
+            VolumeId32::parse_blkid(r#"UUID="A1A2-A3A4"#),
+            Err(Error(ErrorKind::ParseWrapper))
+        );
+    }
+
+    #[test]
+    fn test_volumeid32_parser() {
+        let mut parser = VolumeId32Parser::new();
+        assert!(parser.is_empty());
+
+        for c in "a1a2".chars() {
+            assert_eq!(parser.push(c), Ok(None));
+        }
+        assert_eq!(
+            parser.push('g'),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'g',
+                index: 5,
+            }))
+        );
+        assert_eq!(parser.len(), 4);
+
+        assert_eq!(parser.push('-'), Ok(None));
+        assert_eq!(
+            parser.push('-'),
+            Err(Error(ErrorKind::ParseSeparatorPosition { index: 6 }))
+        );
+        for c in "A3a".chars() {
+            assert_eq!(parser.push(c), Ok(None));
+        }
+        assert_eq!(parser.push('4'), VolumeId32::parse("a1a2a3a4").map(Some));
+        assert_eq!(parser.len(), 9);
+
+        assert_eq!(
+            parser.push('0'),
+            Err(Error(ErrorKind::ParseChar {
+                character: '0',
+                index: 10,
             }))
         );
+
+        parser.reset();
+        assert!(parser.is_empty());
+        assert_eq!(
+            parser.push('-'),
+            Err(Error(ErrorKind::ParseSeparatorPosition { index: 1 }))
+        );
+        let mut result = Ok(None);
+        for c in "6ddcf6da".chars() {
+            result = parser.push(c);
+        }
+        assert_eq!(result, VolumeId32::parse("6ddcf6da").map(Some));
+    }
+
+    #[test]
+    fn test_parse_kv() {
+        let v = VolumeId32::parse("a1a2a3a4").unwrap();
+
+        assert_eq!(VolumeId32::parse_kv("serial=a1a2a3a4", "serial"), Ok(v));
+        assert_eq!(
+            VolumeId32::parse_kv("serial = A1A2-A3A4\n", "serial"),
+            Ok(v)
+        );
+
+        assert_eq!(
+            VolumeId32::parse_kv("label=a1a2a3a4", "serial"),
+            Err(Error(ErrorKind::ParseKey))
+        );
+        assert_eq!(
+            VolumeId32::parse_kv("serial a1a2a3a4", "serial"),
+            Err(Error(ErrorKind::ParseMissingDelimiter { delimiter: '=' }))
+        );
+        assert!(VolumeId32::parse_kv("serial=", "serial").is_err());
     }
 }
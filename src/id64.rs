@@ -1,11 +1,16 @@
 mod error;
 pub mod fmt;
+#[cfg(feature = "schemars")]
+mod json_schema;
 mod parser;
 #[cfg(feature = "serde")]
 pub mod serde;
 
 use crate::id64::error::ErrorKind;
-pub use crate::id64::error::{Error, InvalidVolumeId64};
+pub use crate::id64::{
+    error::{Error, InvalidVolumeId64},
+    parser::VolumeId64Parser,
+};
 
 /// 64-bit Volume ID used in NTFS filesystems.
 ///
@@ -48,6 +53,16 @@ pub use crate::id64::error::{Error, InvalidVolumeId64};
 pub struct VolumeId64([u8; 8]);
 
 impl VolumeId64 {
+    /// The simple string of [`nil`], `0000000000000000`.
+    ///
+    /// [`nil`]: #method.nil
+    pub const NIL_STR: &'static str = "0000000000000000";
+
+    /// The simple string of [`max`], `ffffffffffffffff`.
+    ///
+    /// [`max`]: #method.max
+    pub const MAX_STR: &'static str = "ffffffffffffffff";
+
     /// A VolumeId64 with all zeros.
     /// Basic usage:
     ///
@@ -190,6 +205,50 @@ impl VolumeId64 {
         return Ok(VolumeId64::from_bytes_be(bytes));
     }
 
+    /// Creates a VolumeId64 using the supplied bytes, usable in const context.
+    ///
+    /// This is the same as [`from_slice`], but copies the bytes with a loop
+    /// so that it can be evaluated at compile time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `b` has any length other than 8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// const VOLUMEID64: VolumeId64 = match VolumeId64::from_slice_const(&[
+    ///     0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8,
+    /// ]) {
+    ///     Ok(v) => v,
+    ///     Err(_) => panic!(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "a1a2a3a4a5a6a7a8",
+    ///     VOLUMEID64.to_string(),
+    /// );
+    /// ```
+    ///
+    /// [`from_slice`]: #method.from_slice
+    pub const fn from_slice_const(b: &[u8]) -> Result<Self, Error> {
+        if b.len() != 8 {
+            return Err(Error(ErrorKind::ParseByteLength { len: b.len() }));
+        }
+
+        let mut bytes = [0u8; 8];
+        let mut i = 0;
+        while i < 8 {
+            bytes[i] = b[i];
+            i += 1;
+        }
+
+        return Ok(VolumeId64::from_bytes(bytes));
+    }
+
     /// Creates a VolumeId64 from a 64bit value.
     ///
     /// # Examples
@@ -207,7 +266,7 @@ impl VolumeId64 {
     ///     volumeid64.simple().to_string(),
     /// );
     /// ```
-    pub fn from_u64(v: u64) -> Self {
+    pub const fn from_u64(v: u64) -> Self {
         VolumeId64::from_bytes(v.to_le_bytes())
     }
 
@@ -232,7 +291,7 @@ impl VolumeId64 {
     ///     volumeid64.simple().to_string(),
     /// );
     /// ```
-    pub fn from_u64_be(v: u64) -> Self {
+    pub const fn from_u64_be(v: u64) -> Self {
         VolumeId64::from_bytes(v.to_be_bytes())
     }
 
@@ -256,6 +315,30 @@ impl VolumeId64 {
         u64::from_le_bytes(*self.as_bytes())
     }
 
+    /// Returns the integer you read off the displayed VolumeId64, so
+    /// `a1a2a3a4a5a6a7a8` gives `0xa1a2a3a4a5a6a7a8`.
+    ///
+    /// This reads the stored bytes as big-endian, so it is the byte-swapped
+    /// value of [`as_u64`] on every target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8")
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid64.display_value(),
+    ///     0xa1a2a3a4a5a6a7a8,
+    /// );
+    /// ```
+    ///
+    /// [`as_u64`]: #method.as_u64
+    pub const fn display_value(&self) -> u64 {
+        u64::from_be_bytes(*self.as_bytes())
+    }
+
     /// Returns a 64bit big-endian value containing the value.
     ///
     /// The bytes in the `u64` will be flipped to convert into little-endian
@@ -297,7 +380,7 @@ impl VolumeId64 {
     ///     volumeid64.simple().to_string(),
     /// );
     /// ```
-    pub fn from_u32_pair(high_bits: u32, low_bits: u32) -> Self {
+    pub const fn from_u32_pair(high_bits: u32, low_bits: u32) -> Self {
         VolumeId64::from_u64(((high_bits as u64) << 32) | low_bits as u64)
     }
 
@@ -319,7 +402,7 @@ impl VolumeId64 {
     ///     volumeid64.simple().to_string(),
     /// );
     /// ```
-    pub fn from_u32_pair_be(high_bits: u32, low_bits: u32) -> Self {
+    pub const fn from_u32_pair_be(high_bits: u32, low_bits: u32) -> Self {
         VolumeId64::from_u64_be(((high_bits as u64) << 32) | low_bits as u64)
     }
 
@@ -389,6 +472,23 @@ impl VolumeId64 {
         ]
     }
 
+    /// Tests if the VolumeId64 equals the integer `v`, as returned by [`as_u64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// const VOLUMEID64: VolumeId64 = VolumeId64::from_u64(0xa8a7a6a5a4a3a2a1);
+    /// const _: () = assert!(VOLUMEID64.eq_u64(0xa8a7a6a5a4a3a2a1));
+    ///
+    /// assert_eq!(VOLUMEID64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    ///
+    /// [`as_u64`]: #method.as_u64
+    pub const fn eq_u64(&self, v: u64) -> bool {
+        self.as_u64() == v
+    }
+
     /// Tests if the VolumeId64 is nil (all zeros).
     pub const fn is_nil(&self) -> bool {
         self.as_u64() == u64::MIN
@@ -398,54 +498,1910 @@ impl VolumeId64 {
     pub const fn is_max(&self) -> bool {
         self.as_u64() == u64::MAX
     }
-}
 
-impl crate::std::hash::Hash for VolumeId64 {
-    fn hash<H: crate::std::hash::Hasher>(&self, state: &mut H) {
-        state.write(&self.0);
+    /// Tests if two VolumeId64s share the same leading `prefix_bits` bits of
+    /// their displayed value.
+    ///
+    /// This is useful when deciding how many hex digits must be shown to tell
+    /// two serials apart, much like abbreviated git hashes. A `prefix_bits`
+    /// of 0 always collides, while values of 64 or more compare the whole
+    /// serial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let a = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    /// let b = VolumeId64::parse("a1a2a3a4b5b6b7b8").unwrap();
+    ///
+    /// assert!(a.short_collides(&b, 32));
+    /// assert!(!a.short_collides(&b, 64));
+    /// ```
+    pub const fn short_collides(&self, other: &Self, prefix_bits: u32) -> bool {
+        let diff = self.display_value() ^ other.display_value();
+        match diff.checked_shr(64u32.saturating_sub(prefix_bits)) {
+            Some(v) => v == 0,
+            None => true,
+        }
     }
-}
 
-impl AsRef<VolumeId64> for VolumeId64 {
-    #[inline]
-    fn as_ref(&self) -> &VolumeId64 {
-        self
+    /// Returns the absolute numeric distance between two VolumeId64s.
+    ///
+    /// The distance is computed from the [`as_u64`] values of both serials,
+    /// so it is the same regardless of which one is larger.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let a = VolumeId64::from_u64(10);
+    /// let b = VolumeId64::from_u64(15);
+    ///
+    /// assert_eq!(a.numeric_distance(&b), 5);
+    /// assert_eq!(b.numeric_distance(&a), 5);
+    /// ```
+    ///
+    /// [`as_u64`]: #method.as_u64
+    pub const fn numeric_distance(&self, other: &Self) -> u64 {
+        self.as_u64().abs_diff(other.as_u64())
     }
-}
 
-impl AsRef<[u8]> for VolumeId64 {
-    #[inline]
-    fn as_ref(&self) -> &[u8] {
-        &self.0
+    /// Tests if all eight bytes of the VolumeId64 are printable ASCII.
+    ///
+    /// Serials are normally random, but occasionally one is set from a string
+    /// and its bytes spell out readable text. Printable ASCII covers the space
+    /// character through `~`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("4e54465320202020").unwrap();
+    ///
+    /// assert!(volumeid64.looks_ascii());
+    /// assert!(!VolumeId64::nil().looks_ascii());
+    /// ```
+    pub const fn looks_ascii(&self) -> bool {
+        let mut i = 0;
+        while i < self.0.len() {
+            if !matches!(self.0[i], b' '..=b'~') {
+                return false;
+            }
+            i += 1;
+        }
+
+        return true;
     }
-}
 
-impl TryFrom<&[u8]> for VolumeId64 {
-    type Error = Error;
+    /// Returns the bytes of the VolumeId64 as a string slice if they are all
+    /// printable ASCII, see [`looks_ascii`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("4e54465320202020").unwrap();
+    ///
+    /// assert_eq!(volumeid64.as_ascii_str(), Some("NTFS    "));
+    /// assert_eq!(VolumeId64::max().as_ascii_str(), None);
+    /// ```
+    ///
+    /// [`looks_ascii`]: #method.looks_ascii
+    pub const fn as_ascii_str(&self) -> Option<&str> {
+        if !self.looks_ascii() {
+            return None;
+        }
 
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        VolumeId64::from_slice(value)
+        match crate::std::str::from_utf8(&self.0) {
+            Ok(s) => Some(s),
+            Err(_) => None,
+        }
     }
-}
 
-#[cfg(feature = "alloc")]
-impl From<VolumeId64> for alloc::vec::Vec<u8> {
-    fn from(value: VolumeId64) -> Self {
-        value.0.to_vec()
+    /// Returns the VolumeId64 with its bytes rotated left by `n` places.
+    ///
+    /// The byte at index `n` becomes the first byte, wrapping around so that
+    /// rotating by a multiple of 8 returns the same VolumeId64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.rotate_bytes(1).to_string(), "a2a3a4a5a6a7a8a1");
+    /// assert_eq!(volumeid64.rotate_bytes(8), volumeid64);
+    /// ```
+    pub const fn rotate_bytes(self, n: usize) -> Self {
+        let n = n % 8;
+        let mut bytes = [0u8; 8];
+        let mut i = 0;
+
+        while i < 8 {
+            bytes[i] = self.0[(i + n) % 8];
+            i += 1;
+        }
+
+        return VolumeId64(bytes);
     }
-}
 
-#[cfg(feature = "alloc")]
-impl TryFrom<alloc::vec::Vec<u8>> for VolumeId64 {
-    type Error = Error;
+    /// Derives a VolumeId64 from a string label.
+    ///
+    /// The label is hashed with 64-bit FNV-1a, so the same label always
+    /// produces the same VolumeId64, across runs and platforms. This is not a
+    /// cryptographic hash and different labels are not guaranteed to produce
+    /// different serials.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let a = VolumeId64::from_label("backup-disk");
+    /// let b = VolumeId64::from_label("backup-disk");
+    ///
+    /// assert_eq!(a, b);
+    /// assert_ne!(a, VolumeId64::from_label("boot-disk"));
+    /// ```
+    pub const fn from_label(label: &str) -> Self {
+        VolumeId64::from_content_hash(label.as_bytes())
+    }
 
-    fn try_from(value: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
-        VolumeId64::from_slice(&value)
+    /// Derives a VolumeId64 from arbitrary bytes, such as the contents of a
+    /// disk image.
+    ///
+    /// `data` is hashed with 64-bit FNV-1a, the same as [`from_label`], so
+    /// identical content always produces the same VolumeId64, across runs and
+    /// platforms. This is not a cryptographic hash: it is easy to find
+    /// different content with the same serial, so it must not be used to verify
+    /// content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let a = VolumeId64::from_content_hash(&[0xeb, 0x58, 0x90]);
+    /// let b = VolumeId64::from_content_hash(&[0xeb, 0x58, 0x90]);
+    ///
+    /// assert_eq!(a, b);
+    /// assert_eq!(
+    ///     VolumeId64::from_content_hash(b"boot-disk"),
+    ///     VolumeId64::from_label("boot-disk"),
+    /// );
+    /// ```
+    ///
+    /// [`from_label`]: #method.from_label
+    pub const fn from_content_hash(data: &[u8]) -> Self {
+        VolumeId64::from_u64(crate::common::fnv1a_64(data))
     }
-}
 
-impl Default for VolumeId64 {
-    fn default() -> Self {
-        Self::nil()
+    /// Returns the lesser of two VolumeId64s, comparing their bytes
+    /// lexicographically.
+    ///
+    /// This gives the same result as [`Ord::min`], but can be used in `const`
+    /// contexts. If both are equal, `self` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// const A: VolumeId64 = VolumeId64::nil();
+    /// const B: VolumeId64 = VolumeId64::max();
+    /// const MIN: VolumeId64 = B.min_bytes(A);
+    ///
+    /// assert_eq!(MIN, A);
+    /// ```
+    pub const fn min_bytes(self, other: Self) -> Self {
+        if self.bytes_gt(&other) { other } else { self }
+    }
+
+    /// Returns the greater of two VolumeId64s, comparing their bytes
+    /// lexicographically.
+    ///
+    /// This gives the same result as [`Ord::max`], but can be used in `const`
+    /// contexts. If both are equal, `other` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// const A: VolumeId64 = VolumeId64::nil();
+    /// const B: VolumeId64 = VolumeId64::max();
+    /// const MAX: VolumeId64 = A.max_bytes(B);
+    ///
+    /// assert_eq!(MAX, B);
+    /// ```
+    pub const fn max_bytes(self, other: Self) -> Self {
+        if self.bytes_gt(&other) { self } else { other }
+    }
+
+    const fn bytes_gt(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < 8 {
+            if self.0[i] != other.0[i] {
+                return self.0[i] > other.0[i];
+            }
+            i += 1;
+        }
+
+        return false;
+    }
+
+    /// Derives a child VolumeId64 by XORing each byte with `salt`.
+    ///
+    /// The derivation is reversible: deriving the child again with the same
+    /// salt gives back the original VolumeId64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let parent = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    /// let salt = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// let child = parent.derive(salt);
+    ///
+    /// assert_ne!(child, parent);
+    /// assert_eq!(child.derive(salt), parent);
+    /// ```
+    pub const fn derive(&self, salt: VolumeId64) -> VolumeId64 {
+        let mut bytes = self.0;
+        let mut i = 0;
+
+        while i < 8 {
+            bytes[i] ^= salt.0[i];
+            i += 1;
+        }
+
+        return VolumeId64(bytes);
+    }
+
+    /// Get the byte at index `i` of the VolumeId64, in the order it is stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than 8. See [`get_byte`] for a
+    /// non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.byte(1), 0xa2);
+    /// ```
+    ///
+    /// [`get_byte`]: #method.get_byte
+    pub const fn byte(&self, i: usize) -> u8 {
+        self.0[i]
+    }
+
+    /// Get the byte at index `i` of the VolumeId64, or `None` if `i` is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.get_byte(0), Some(0xa1));
+    /// assert_eq!(volumeid64.get_byte(8), None);
+    /// ```
+    pub const fn get_byte(&self, i: usize) -> Option<u8> {
+        if i < 8 { Some(self.0[i]) } else { None }
+    }
+
+    /// Anonymizes the VolumeId64 with `key`, giving a VolumeId64 that can be
+    /// shared in place of the original, for example in published disk images.
+    ///
+    /// The VolumeId64 is XORed with a mask derived from `key`, so the result
+    /// is deterministic and [`deanonymize`] with the same key reverses it.
+    ///
+    /// This is obfuscation, not encryption: anyone who learns one original
+    /// and anonymized pair for a key can reverse every other serial anonymized
+    /// with that key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6da1b2c3d4e").unwrap();
+    ///
+    /// let anonymized = volumeid64.anonymize(42);
+    ///
+    /// assert_ne!(anonymized, volumeid64);
+    /// assert_eq!(anonymized.deanonymize(42), volumeid64);
+    /// ```
+    ///
+    /// [`deanonymize`]: #method.deanonymize
+    pub const fn anonymize(&self, key: u64) -> VolumeId64 {
+        self.derive(VolumeId64::anonymize_mask(key))
+    }
+
+    /// Reverses [`anonymize`] with the same `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6da1b2c3d4e").unwrap();
+    ///
+    /// assert_eq!(volumeid64.anonymize(7).deanonymize(7), volumeid64);
+    /// ```
+    ///
+    /// [`anonymize`]: #method.anonymize
+    pub const fn deanonymize(&self, key: u64) -> VolumeId64 {
+        self.derive(VolumeId64::anonymize_mask(key))
+    }
+
+    const fn anonymize_mask(key: u64) -> VolumeId64 {
+        VolumeId64::from_u64(crate::common::fnv1a_64(&key.to_le_bytes()))
+    }
+
+    /// Reads the serial number from an NTFS boot sector.
+    ///
+    /// The serial is stored little-endian at offset `0x48`. Since the sector
+    /// is a fixed-size array this can't fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let mut bs = [0u8; 512];
+    /// bs[0x48..0x50].copy_from_slice(&[0x38, 0xfd, 0xc2, 0xfd, 0xda, 0xf6, 0xdc, 0x6d]);
+    ///
+    /// let volumeid64 = VolumeId64::from_ntfs_sector(&bs);
+    ///
+    /// assert_eq!(volumeid64.as_u64(), 0x6ddcf6dafdc2fd38);
+    /// ```
+    pub const fn from_ntfs_sector(bs: &[u8; 512]) -> Self {
+        let offset = crate::fs::NTFS_SERIAL_OFFSET;
+        let mut bytes = [0u8; 8];
+        let mut i = 0;
+
+        while i < 8 {
+            bytes[i] = bs[offset + i];
+            i += 1;
+        }
+
+        VolumeId64(bytes)
+    }
+
+    /// Tests if `field` holds this VolumeId64 in either byte order.
+    ///
+    /// This is a lenient check for tools that aren't sure how an image stored
+    /// its serial number. It accepts the bytes as stored and their reverse, so
+    /// it can match serials that a strict comparison would reject.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    ///
+    /// assert!(volumeid64.matches_disk_field([0x6d, 0xdc, 0xf6, 0xda, 0xfd, 0xc2, 0xfd, 0x38]));
+    /// assert!(volumeid64.matches_disk_field([0x38, 0xfd, 0xc2, 0xfd, 0xda, 0xf6, 0xdc, 0x6d]));
+    /// ```
+    pub const fn matches_disk_field(&self, field: [u8; 8]) -> bool {
+        self.as_u64() == u64::from_le_bytes(field) || self.as_u64() == u64::from_be_bytes(field)
+    }
+
+    /// Returns which of `n` buckets the VolumeId64 falls into, computed as
+    /// [`as_u64`] modulo `n`.
+    ///
+    /// Using [`as_u64`] means the bucket is based on the on-disk byte order
+    /// on every target. If `n` is 0 there are no buckets and 0 is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_u64(10);
+    ///
+    /// assert_eq!(volumeid64.bucket(4), 2);
+    /// assert_eq!(volumeid64.bucket(0), 0);
+    /// ```
+    ///
+    /// [`as_u64`]: #method.as_u64
+    pub const fn bucket(&self, n: u64) -> u64 {
+        match self.as_u64().checked_rem(n) {
+            Some(bucket) => bucket,
+            None => 0,
+        }
+    }
+
+    /// Computes the IEEE CRC32 of the 8 stored bytes of the VolumeId64.
+    ///
+    /// This is the CRC32 used by zlib, Ethernet and PNG, with the reflected
+    /// polynomial `0xEDB88320`, an initial value of `0xFFFFFFFF` and a final
+    /// XOR of `0xFFFFFFFF`. It's useful for writing checksummed records
+    /// without another dependency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// assert_eq!(VolumeId64::nil().crc32(), 0x6522df69);
+    /// ```
+    pub const fn crc32(&self) -> u32 {
+        crate::common::crc32(&self.0)
+    }
+
+    /// Writes the 8 stored bytes of the VolumeId64 into the start of `dst`.
+    ///
+    /// Any bytes of `dst` past the first 8 are left untouched. See
+    /// [`copy_into_le`] to write the bytes in reverse order.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `dst` is shorter than 8 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    /// let mut dst = [0u8; 10];
+    ///
+    /// volumeid64.copy_into(&mut dst).expect("Destination should be large enough");
+    ///
+    /// assert_eq!(dst, [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0, 0]);
+    /// ```
+    ///
+    /// [`copy_into_le`]: #method.copy_into_le
+    pub fn copy_into(&self, dst: &mut [u8]) -> Result<(), Error> {
+        match dst.get_mut(..8) {
+            Some(dst) => {
+                dst.copy_from_slice(&self.0);
+                return Ok(());
+            }
+            None => {
+                return Err(Error(ErrorKind::BufferTooSmall {
+                    len: dst.len(),
+                    needed: 8,
+                }));
+            }
+        }
+    }
+
+    /// Writes the 8 stored bytes of the VolumeId64 into the start of `dst`,
+    /// in reverse order.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `dst` is shorter than 8 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    /// let mut dst = [0u8; 8];
+    ///
+    /// volumeid64.copy_into_le(&mut dst).expect("Destination should be large enough");
+    ///
+    /// assert_eq!(dst, [0xa8, 0xa7, 0xa6, 0xa5, 0xa4, 0xa3, 0xa2, 0xa1]);
+    /// ```
+    pub fn copy_into_le(&self, dst: &mut [u8]) -> Result<(), Error> {
+        self.copy_into(dst)?;
+        dst[..8].reverse();
+
+        return Ok(());
+    }
+
+    /// Returns the 8 stored bytes of the VolumeId64 as decimal octets.
+    ///
+    /// This is the same as [`into_bytes`], named for code that treats the
+    /// serial as dotted decimal octets. See [`dotted`] to display them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.octets(), [161, 162, 163, 164, 165, 166, 167, 168]);
+    /// ```
+    ///
+    /// [`into_bytes`]: #method.into_bytes
+    /// [`dotted`]: #method.dotted
+    #[inline]
+    pub const fn octets(&self) -> [u8; 8] {
+        self.0
+    }
+
+    /// Creates a VolumeId64 from 8 decimal octets, in the order they are
+    /// stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_octets([161, 162, 163, 164, 165, 166, 167, 168]);
+    ///
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    #[inline]
+    pub const fn from_octets(octets: [u8; 8]) -> Self {
+        VolumeId64(octets)
+    }
+
+    /// Creates a VolumeId64 from 8 bytes read from disk, in the byte order
+    /// given by `stored`.
+    ///
+    /// This is [`from_bytes`] for [`Endianness::Little`] and
+    /// [`from_bytes_be`] for [`Endianness::Big`], for readers that determine
+    /// the byte order at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::{Endianness, id64::VolumeId64};
+    /// let bytes = [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8];
+    ///
+    /// let little = VolumeId64::from_field(bytes, Endianness::Little);
+    /// let big = VolumeId64::from_field(bytes, Endianness::Big);
+    ///
+    /// assert_eq!(little.to_string(), "a1a2a3a4a5a6a7a8");
+    /// assert_eq!(big.to_string(), "a8a7a6a5a4a3a2a1");
+    /// ```
+    ///
+    /// [`from_bytes`]: #method.from_bytes
+    /// [`from_bytes_be`]: #method.from_bytes_be
+    /// [`Endianness::Little`]: crate::Endianness::Little
+    /// [`Endianness::Big`]: crate::Endianness::Big
+    pub const fn from_field(bytes: [u8; 8], stored: crate::Endianness) -> Self {
+        match stored {
+            crate::Endianness::Little => VolumeId64::from_bytes(bytes),
+            crate::Endianness::Big => VolumeId64::from_bytes_be(bytes),
+        }
+    }
+
+    /// Combines VolumeId64s into one by XORing them together.
+    ///
+    /// The result doesn't depend on the order of `ids`, so it can be used as a
+    /// fingerprint of a set of volumes. A VolumeId64 that appears twice cancels
+    /// itself out. An empty iterator gives [`nil`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let a = VolumeId64::from_u64(0x6ddcf6dafdc2fd38);
+    /// let b = VolumeId64::from_u64(0xa1a2a3a4a5a6a7a8);
+    ///
+    /// assert_eq!(VolumeId64::xor_all([a, b]), VolumeId64::xor_all([b, a]));
+    /// assert_eq!(VolumeId64::xor_all([a, b, a]), b);
+    /// ```
+    ///
+    /// [`nil`]: #method.nil
+    pub fn xor_all<I: IntoIterator<Item = VolumeId64>>(ids: I) -> VolumeId64 {
+        ids.into_iter()
+            .fold(VolumeId64::nil(), |acc, id| acc.derive(id))
+    }
+
+    /// Combines VolumeId64s into one by adding their [`as_u64`] values,
+    /// wrapping on overflow.
+    ///
+    /// Unlike [`xor_all`], a VolumeId64 that appears twice doesn't cancel
+    /// itself out. An empty iterator gives [`nil`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let a = VolumeId64::from_u64(1);
+    /// let b = VolumeId64::from_u64(u64::MAX);
+    ///
+    /// assert_eq!(VolumeId64::sum_wrapping([a, a]).as_u64(), 2);
+    /// assert_eq!(VolumeId64::sum_wrapping([a, b]), VolumeId64::nil());
+    /// ```
+    ///
+    /// [`as_u64`]: #method.as_u64
+    /// [`xor_all`]: #method.xor_all
+    /// [`nil`]: #method.nil
+    pub fn sum_wrapping<I: IntoIterator<Item = VolumeId64>>(ids: I) -> VolumeId64 {
+        VolumeId64::from_u64(
+            ids.into_iter()
+                .fold(0, |acc: u64, id| acc.wrapping_add(id.as_u64())),
+        )
+    }
+
+    /// Returns the low 32 bits of the VolumeId64 as a [`VolumeId32`], which is
+    /// how Windows shows an NTFS serial as `XXXX-XXXX` in `vol` and Explorer.
+    ///
+    /// This loses the high 32 bits, so different NTFS serials can give the
+    /// same short serial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_u64(0x1b2c3d4e_6ddcf6da);
+    ///
+    /// assert_eq!(volumeid64.windows_short().as_u32(), 0x6ddcf6da);
+    /// ```
+    ///
+    /// [`VolumeId32`]: crate::id32::VolumeId32
+    #[cfg(feature = "id32")]
+    pub const fn windows_short(&self) -> crate::id32::VolumeId32 {
+        crate::id32::VolumeId32::from_bytes([self.0[0], self.0[1], self.0[2], self.0[3]])
+    }
+
+    /// Views a borrowed byte array as a VolumeId64, without copying.
+    ///
+    /// VolumeId64 is `#[repr(transparent)]` over `[u8; 8]`, so it has the
+    /// same size, alignment and validity as the array and the reference can be
+    /// cast directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let bytes = [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8];
+    ///
+    /// let volumeid64 = VolumeId64::from_bytes_ref(&bytes);
+    ///
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    #[inline]
+    pub const fn from_bytes_ref(b: &[u8; 8]) -> &VolumeId64 {
+        // SAFETY: VolumeId64 is `#[repr(transparent)]` over `[u8; 8]`
+        unsafe { &*(b as *const [u8; 8] as *const VolumeId64) }
+    }
+
+    /// Tests if all 8 bytes of the VolumeId64 are the same, like `abababababababab`.
+    ///
+    /// This includes [`nil`] and [`max`]. Such serials are unlikely to be
+    /// random, and usually come from formatting tools that didn't set one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// assert!(VolumeId64::from_bytes([0xab; 8]).is_all_same_byte());
+    /// assert!(!VolumeId64::from_u64(1).is_all_same_byte());
+    /// ```
+    ///
+    /// [`nil`]: #method.nil
+    /// [`max`]: #method.max
+    pub const fn is_all_same_byte(&self) -> bool {
+        let mut i = 1;
+        while i < 8 {
+            if self.0[i] != self.0[0] {
+                return false;
+            }
+            i += 1;
+        }
+
+        return true;
+    }
+
+    /// Returns the indices of the bytes that differ between the VolumeId64
+    /// and `other`, in increasing order.
+    ///
+    /// The indices are positions in the stored bytes, as returned by
+    /// [`as_bytes`]. This doesn't allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let a = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+    /// let b = VolumeId64::from_bytes([0xa1, 0xff, 0xa3, 0xa4, 0xa5, 0xa6, 0x00, 0xa8]);
+    ///
+    /// assert!(a.diff_positions(&b).eq([1, 6]));
+    /// assert_eq!(a.diff_positions(&a).count(), 0);
+    /// ```
+    ///
+    /// [`as_bytes`]: #method.as_bytes
+    pub fn diff_positions(&self, other: &Self) -> impl Iterator<Item = usize> + use<> {
+        let (a, b) = (self.0, other.0);
+        (0..8).filter(move |&i| a[i] != b[i])
+    }
+
+    /// Returns how many different byte values the VolumeId64 contains, from
+    /// 1 to 8.
+    ///
+    /// A low count, like 1 for `aaaaaaaaaaaaaaaa`, suggests the serial wasn't
+    /// generated randomly. Unlike [`is_all_same_byte`], this also shows
+    /// serials that only repeat some of their bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let repeated = VolumeId64::from_bytes([0xaa; 8]);
+    /// let distinct = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+    ///
+    /// assert_eq!(repeated.distinct_byte_count(), 1);
+    /// assert_eq!(distinct.distinct_byte_count(), 8);
+    /// ```
+    ///
+    /// [`is_all_same_byte`]: #method.is_all_same_byte
+    pub const fn distinct_byte_count(&self) -> u8 {
+        let mut count = 0;
+        let mut i = 0;
+        while i < 8 {
+            let mut j = 0;
+            while j < i && self.0[j] != self.0[i] {
+                j += 1;
+            }
+            if j == i {
+                count += 1;
+            }
+            i += 1;
+        }
+
+        return count;
+    }
+
+    /// Returns a copy of the VolumeId64 with some of its hex digits set to
+    /// zero, for partially redacting a serial while keeping its shape.
+    ///
+    /// Bit `i` of `mask` selects the `i`th of the 16 hex digits shown by
+    /// [`Display`], counting from the left and starting at 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.redact_nibbles(0x00ff).to_string(), "00000000a5a6a7a8");
+    /// ```
+    ///
+    /// [`Display`]: core::fmt::Display
+    pub const fn redact_nibbles(&self, mask: u16) -> VolumeId64 {
+        let mut bytes = self.0;
+        let mut i = 0;
+        while i < 16 {
+            if mask & (1 << i) != 0 {
+                bytes[i / 2] &= if i % 2 == 0 { 0x0f } else { 0xf0 };
+            }
+            i += 1;
+        }
+
+        return VolumeId64(bytes);
+    }
+
+    /// Returns the 8 stored bytes of the VolumeId64 followed by a parity
+    /// byte, the XOR of those bytes.
+    ///
+    /// This lets a receiver detect a corrupted byte with
+    /// [`from_parity_checked`]. It can't detect every error, such as the
+    /// same bit flipping in two bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid64.with_parity(),
+    ///     [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0x08],
+    /// );
+    /// ```
+    ///
+    /// [`from_parity_checked`]: #method.from_parity_checked
+    pub const fn with_parity(&self) -> [u8; 9] {
+        let mut bytes = [0u8; 9];
+        let mut i = 0;
+        while i < 8 {
+            bytes[i] = self.0[i];
+            bytes[8] ^= self.0[i];
+            i += 1;
+        }
+
+        return bytes;
+    }
+
+    /// Creates a VolumeId64 from bytes produced by [`with_parity`], checking
+    /// the parity byte.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the last byte isn't the XOR of
+    /// the other 8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_u64(0x6ddcf6da);
+    /// let mut bytes = volumeid64.with_parity();
+    ///
+    /// assert_eq!(VolumeId64::from_parity_checked(&bytes), Ok(volumeid64));
+    ///
+    /// bytes[1] ^= 0x10;
+    /// assert!(VolumeId64::from_parity_checked(&bytes).is_err());
+    /// ```
+    ///
+    /// [`with_parity`]: #method.with_parity
+    pub fn from_parity_checked(bytes: &[u8; 9]) -> Result<Self, Error> {
+        let mut serial = [0u8; 8];
+        serial.copy_from_slice(&bytes[..8]);
+
+        let volumeid64 = VolumeId64::from_bytes(serial);
+        let expected = volumeid64.with_parity()[8];
+        if bytes[8] != expected {
+            return Err(Error(ErrorKind::ParseParity {
+                expected,
+                found: bytes[8],
+            }));
+        }
+
+        return Ok(volumeid64);
+    }
+
+    /// Tests if the VolumeId64 matches `pattern`, a string of 16 hex digits
+    /// or `?` wildcards like `a1??a3a4a5a6a7a8`.
+    ///
+    /// Each character of `pattern` is compared with the digit in the same
+    /// position of the simple format, ignoring case, and `?` matches any
+    /// digit. A pattern that isn't 16 characters long never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert!(volumeid64.matches_pattern("a1??a3a4a5a6a7a8"));
+    /// assert!(!volumeid64.matches_pattern("b1??????????????"));
+    /// ```
+    pub fn matches_pattern(&self, pattern: &str) -> bool {
+        let pattern = pattern.as_bytes();
+        if pattern.len() != 16 {
+            return false;
+        }
+
+        self.simple_str()
+            .bytes()
+            .zip(pattern)
+            .all(|(digit, &p)| p == b'?' || p.eq_ignore_ascii_case(&digit))
+    }
+
+    /// Tests if the VolumeId64 equals `other`, either as is or with the
+    /// order of its bytes reversed.
+    ///
+    /// This is for reconciling serials read by tools that may disagree on
+    /// endianness. To compare a VolumeId64 with raw bytes from disk, see
+    /// [`matches_disk_field`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let a = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+    /// let b = VolumeId64::from_bytes([0xa8, 0xa7, 0xa6, 0xa5, 0xa4, 0xa3, 0xa2, 0xa1]);
+    ///
+    /// assert!(a.eq_byteswapped(&b));
+    /// assert!(a.eq_byteswapped(&a));
+    /// assert!(!a.eq_byteswapped(&VolumeId64::nil()));
+    /// ```
+    ///
+    /// [`matches_disk_field`]: #method.matches_disk_field
+    pub const fn eq_byteswapped(&self, other: &Self) -> bool {
+        self.as_u64() == other.as_u64() || self.as_u64() == other.as_u64().swap_bytes()
+    }
+
+    /// Returns a color derived from the VolumeId64, as red, green and blue
+    /// components.
+    ///
+    /// Stored byte `i` is XORed into component `i % 3`, so red is
+    /// `b[0] ^ b[3] ^ b[6]`, green is `b[1] ^ b[4] ^ b[7]` and blue is
+    /// `b[2] ^ b[5]`. Every byte affects the color, the same VolumeId64 always
+    /// gives the same color, and [`nil`] is black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_bytes([0x10, 0x20, 0x30, 0x01, 0x02, 0x03, 0x40, 0x50]);
+    ///
+    /// assert_eq!(volumeid64.to_rgb(), (0x51, 0x72, 0x33));
+    /// assert_eq!(VolumeId64::nil().to_rgb(), (0, 0, 0));
+    /// ```
+    ///
+    /// [`nil`]: #method.nil
+    pub const fn to_rgb(&self) -> (u8, u8, u8) {
+        let mut rgb = [0u8; 3];
+        let mut i = 0;
+        while i < 8 {
+            rgb[i % 3] ^= self.0[i];
+            i += 1;
+        }
+
+        return (rgb[0], rgb[1], rgb[2]);
+    }
+
+    /// Returns the [`as_u64`] value of the VolumeId64 reinterpreted as a
+    /// signed integer, for storage that only has signed 64-bit columns.
+    ///
+    /// The bits are unchanged, so serials with the high bit set become
+    /// negative. Use [`from_i64`] to get the VolumeId64 back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_u64(0xa1a2a3a4a5a6a7a8);
+    ///
+    /// assert_eq!(volumeid64.as_i64(), -6799692559826901080);
+    /// assert_eq!(VolumeId64::from_i64(-6799692559826901080), volumeid64);
+    /// ```
+    ///
+    /// [`as_u64`]: #method.as_u64
+    /// [`from_i64`]: #method.from_i64
+    pub const fn as_i64(&self) -> i64 {
+        self.as_u64() as i64
+    }
+
+    /// Creates a VolumeId64 from a signed integer produced by [`as_i64`].
+    ///
+    /// The bits of `v` are reinterpreted as the [`as_u64`] value, so
+    /// this is the same as `from_u64(v as u64)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// assert_eq!(VolumeId64::from_i64(-1), VolumeId64::max());
+    /// ```
+    ///
+    /// [`as_i64`]: #method.as_i64
+    /// [`as_u64`]: #method.as_u64
+    pub const fn from_i64(v: i64) -> Self {
+        VolumeId64::from_u64(v as u64)
+    }
+
+    /// Tests if the [`as_u64`] value of the VolumeId64 is between those of
+    /// `low` and `high`, inclusive.
+    ///
+    /// This compares the numeric values, not the stored bytes like the derived
+    /// [`Ord`]. If `low` is greater than `high` the range is empty, so this
+    /// returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let low = VolumeId64::from_u64(0x100);
+    /// let high = VolumeId64::from_u64(0x1ff);
+    ///
+    /// assert!(VolumeId64::from_u64(0x180).in_range(low, high));
+    /// assert!(!VolumeId64::from_u64(0x200).in_range(low, high));
+    /// assert!(!VolumeId64::from_u64(0x180).in_range(high, low));
+    /// ```
+    ///
+    /// [`as_u64`]: #method.as_u64
+    pub const fn in_range(&self, low: VolumeId64, high: VolumeId64) -> bool {
+        let value = self.as_u64();
+        return low.as_u64() <= value && value <= high.as_u64();
+    }
+}
+
+impl crate::std::hash::Hash for VolumeId64 {
+    fn hash<H: crate::std::hash::Hasher>(&self, state: &mut H) {
+        state.write(&self.0);
+    }
+}
+
+impl AsRef<VolumeId64> for VolumeId64 {
+    #[inline]
+    fn as_ref(&self) -> &VolumeId64 {
+        self
+    }
+}
+
+impl AsRef<[u8]> for VolumeId64 {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for VolumeId64 {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        VolumeId64::from_slice(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<VolumeId64> for alloc::vec::Vec<u8> {
+    fn from(value: VolumeId64) -> Self {
+        value.0.to_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<alloc::vec::Vec<u8>> for VolumeId64 {
+    type Error = Error;
+
+    fn try_from(value: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
+        VolumeId64::from_slice(&value)
+    }
+}
+
+impl Default for VolumeId64 {
+    fn default() -> Self {
+        Self::nil()
+    }
+}
+
+impl crate::VolumeIdentifier for VolumeId64 {
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self, Error> {
+        VolumeId64::parse(input)
+    }
+
+    fn nil() -> Self {
+        VolumeId64::nil()
+    }
+
+    fn max() -> Self {
+        VolumeId64::max()
+    }
+
+    fn is_nil(&self) -> bool {
+        VolumeId64::is_nil(self)
+    }
+
+    fn is_max(&self) -> bool {
+        VolumeId64::is_max(self)
+    }
+}
+
+/// Returns how many of `ids` are degenerate: [`nil`], [`max`] or otherwise
+/// made of a single repeated byte.
+///
+/// This is useful for finding volumes in bulk whose serial was never set
+/// properly. See [`is_all_same_byte`].
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::id64::{VolumeId64, count_degenerate};
+/// let ids = [VolumeId64::nil(), VolumeId64::from_u64(0x1234), VolumeId64::max()];
+///
+/// assert_eq!(count_degenerate(&ids), 2);
+/// ```
+///
+/// [`nil`]: struct.VolumeId64.html#method.nil
+/// [`max`]: struct.VolumeId64.html#method.max
+/// [`is_all_same_byte`]: struct.VolumeId64.html#method.is_all_same_byte
+pub fn count_degenerate(ids: &[VolumeId64]) -> usize {
+    ids.iter().filter(|id| id.is_all_same_byte()).count()
+}
+
+#[cfg(feature = "std")]
+impl VolumeId64 {
+    /// Reads a VolumeId64 from the next 8 bytes of `r`, in the order they
+    /// are stored.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if reading fails. If `r` ends
+    /// before 8 bytes are read, the error has the kind
+    /// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// # use std::io::Cursor;
+    /// let mut cursor = Cursor::new([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+    ///
+    /// let volumeid64 = VolumeId64::from_reader(&mut cursor)
+    ///     .expect("Failed Reading Bytes");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    pub fn from_reader<R: std::io::Read>(r: &mut R) -> std::io::Result<Self> {
+        let mut bytes = [0u8; 8];
+        r.read_exact(&mut bytes)?;
+
+        return Ok(VolumeId64::from_bytes(bytes));
+    }
+
+    /// Writes the 8 bytes of the VolumeId64 to `w` in the byte order `order`.
+    ///
+    /// [`Endianness::Little`] writes the bytes in the order they are stored
+    /// and [`Endianness::Big`] writes them reversed, the inverse of
+    /// [`from_field`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::{Endianness, id64::VolumeId64};
+    /// let volumeid64 = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+    /// let mut buf = Vec::new();
+    ///
+    /// volumeid64
+    ///     .to_writer(&mut buf, Endianness::Big)
+    ///     .expect("Failed Writing Bytes");
+    ///
+    /// assert_eq!(buf, [0xa8, 0xa7, 0xa6, 0xa5, 0xa4, 0xa3, 0xa2, 0xa1]);
+    /// ```
+    ///
+    /// [`Endianness::Little`]: crate::Endianness::Little
+    /// [`Endianness::Big`]: crate::Endianness::Big
+    /// [`from_field`]: #method.from_field
+    pub fn to_writer<W: std::io::Write>(
+        &self,
+        w: &mut W,
+        order: crate::Endianness,
+    ) -> std::io::Result<()> {
+        match order {
+            crate::Endianness::Little => w.write_all(&self.0),
+            crate::Endianness::Big => w.write_all(&self.to_bytes_be()),
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl VolumeId64 {
+    /// The number of values [`new_random_distinct`] tries before giving up.
+    ///
+    /// [`new_random_distinct`]: #method.new_random_distinct
+    pub const RANDOM_DISTINCT_ATTEMPTS: usize = 64;
+
+    /// Generates a random VolumeId64 that isn't in `existing`.
+    ///
+    /// Up to [`RANDOM_DISTINCT_ATTEMPTS`] values are drawn from `rng`, and the
+    /// first one not found in `existing` is returned. Returns `None` if every
+    /// attempt collided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// # use rand_core::SeedableRng;
+    /// let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(1);
+    /// let existing = [VolumeId64::nil(), VolumeId64::max()];
+    ///
+    /// let volumeid64 = VolumeId64::new_random_distinct(&existing, &mut rng)
+    ///     .expect("Failed to generate a distinct VolumeId64");
+    ///
+    /// assert!(!existing.contains(&volumeid64));
+    /// ```
+    ///
+    /// [`RANDOM_DISTINCT_ATTEMPTS`]: #associatedconstant.RANDOM_DISTINCT_ATTEMPTS
+    pub fn new_random_distinct(
+        existing: &[VolumeId64],
+        rng: &mut impl rand_core::RngCore,
+    ) -> Option<Self> {
+        for _ in 0..Self::RANDOM_DISTINCT_ATTEMPTS {
+            let volumeid64 = VolumeId64::from_u64(rng.next_u64());
+
+            if !existing.contains(&volumeid64) {
+                return Some(volumeid64);
+            }
+        }
+
+        return None;
+    }
+}
+
+/// Parses a [`VolumeId64`] from a string literal at compile time.
+///
+/// This is friendlier than unwrapping [`VolumeId64::try_parse`] in a `const`,
+/// and an invalid literal fails the build instead of panicking at runtime.
+///
+/// # Examples
+///
+/// ```
+/// # use fat_volume_id::{const_volume_id64, id64::VolumeId64};
+/// const VOLUME_ID: VolumeId64 = const_volume_id64!("a1a2a3a4a5a6a7a8");
+///
+/// assert_eq!(VOLUME_ID.to_string(), "a1a2a3a4a5a6a7a8");
+/// ```
+///
+/// An invalid literal fails to compile:
+///
+/// ```compile_fail
+/// # use fat_volume_id::{const_volume_id64, id64::VolumeId64};
+/// const VOLUME_ID: VolumeId64 = const_volume_id64!("a1a2a3a4a5a6a7ag");
+/// ```
+#[macro_export]
+macro_rules! const_volume_id64 {
+    ($s:expr) => {{
+        const VOLUME_ID64: $crate::id64::VolumeId64 = match $crate::id64::VolumeId64::try_parse($s)
+        {
+            Ok(volumeid64) => volumeid64,
+            Err(_) => panic!("invalid VolumeId64 literal"),
+        };
+        VOLUME_ID64
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_collides() {
+        let a = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+        let b = VolumeId64::parse("6ddcf6db00000000").unwrap();
+
+        assert!(a.short_collides(&b, 0));
+        assert!(a.short_collides(&b, 16));
+        assert!(a.short_collides(&b, 28));
+        assert!(!a.short_collides(&b, 32));
+        assert!(!a.short_collides(&b, 64));
+        assert!(a.short_collides(&a, 64));
+        assert!(a.short_collides(&a, 80));
+    }
+
+    #[test]
+    fn test_numeric_distance() {
+        let a = VolumeId64::from_u64(10);
+        let b = VolumeId64::from_u64(15);
+
+        assert_eq!(a.numeric_distance(&a), 0);
+        assert_eq!(a.numeric_distance(&b), 5);
+        assert_eq!(b.numeric_distance(&a), 5);
+        assert_eq!(
+            VolumeId64::nil().numeric_distance(&VolumeId64::max()),
+            u64::MAX
+        );
+        assert_eq!(
+            VolumeId64::max().numeric_distance(&VolumeId64::nil()),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_looks_ascii() {
+        let ascii = VolumeId64::from_bytes(*b"MSWIN4.1");
+        assert!(ascii.looks_ascii());
+        assert_eq!(ascii.as_ascii_str(), Some("MSWIN4.1"));
+
+        let binary = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+        assert!(!binary.looks_ascii());
+        assert_eq!(binary.as_ascii_str(), None);
+
+        let control = VolumeId64::parse("4142434445460a47").unwrap();
+        assert!(!control.looks_ascii());
+        assert_eq!(control.as_ascii_str(), None);
+    }
+
+    #[test]
+    fn test_rotate_bytes() {
+        const V: VolumeId64 =
+            VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+        const ROTATED: VolumeId64 = V.rotate_bytes(7);
+
+        assert_eq!(V.rotate_bytes(0), V);
+        assert_eq!(V.rotate_bytes(8), V);
+        assert_eq!(
+            V.rotate_bytes(3).into_bytes(),
+            [0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0xa1, 0xa2, 0xa3]
+        );
+        assert_eq!(
+            ROTATED.into_bytes(),
+            [0xa8, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7]
+        );
+        assert_eq!(V.rotate_bytes(9), V.rotate_bytes(1));
+        assert_eq!(V.rotate_bytes(usize::MAX), V.rotate_bytes(7));
+    }
+
+    #[test]
+    fn test_from_label() {
+        assert_eq!(
+            VolumeId64::from_label("a"),
+            VolumeId64::from_u64(0xaf63dc4c8601ec8c)
+        );
+        assert_eq!(
+            VolumeId64::from_label(""),
+            VolumeId64::from_u64(0xcbf29ce484222325)
+        );
+
+        assert_eq!(
+            VolumeId64::from_label("backup-disk"),
+            VolumeId64::from_label("backup-disk")
+        );
+        assert_ne!(
+            VolumeId64::from_label("backup-disk"),
+            VolumeId64::from_label("backup-disk2")
+        );
+    }
+
+    #[test]
+    fn test_min_max_bytes() {
+        const A: VolumeId64 = match VolumeId64::try_parse("a1a2a3a4a5a6a7a8") {
+            Ok(v) => v,
+            Err(_) => panic!(),
+        };
+        const B: VolumeId64 = match VolumeId64::try_parse("a1a2a3a4b5a6a7a8") {
+            Ok(v) => v,
+            Err(_) => panic!(),
+        };
+        const MIN: VolumeId64 = B.min_bytes(A);
+        const MAX: VolumeId64 = A.max_bytes(B);
+
+        assert_eq!(MIN, A);
+        assert_eq!(MAX, B);
+        assert_eq!(A.min_bytes(B), A.min(B));
+        assert_eq!(B.max_bytes(A), B.max(A));
+        assert_eq!(A.min_bytes(A), A);
+        assert_eq!(A.max_bytes(A), A);
+    }
+
+    #[test]
+    fn test_derive() {
+        let parent = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+        let salt = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(parent.derive(salt).derive(salt), parent);
+        assert_eq!(parent.derive(VolumeId64::nil()), parent);
+        assert_eq!(parent.derive(parent), VolumeId64::nil());
+        assert_eq!(
+            VolumeId64::nil().derive(VolumeId64::max()),
+            VolumeId64::max()
+        );
+    }
+
+    #[test]
+    fn test_byte() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        for i in 0..8 {
+            assert_eq!(v.byte(i), 0xa1 + i as u8);
+            assert_eq!(v.get_byte(i), Some(0xa1 + i as u8));
+        }
+        assert_eq!(v.get_byte(8), None);
+        assert_eq!(v.get_byte(usize::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_byte_out_of_range() {
+        VolumeId64::nil().byte(8);
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let v = VolumeId64::parse("6ddcf6da1b2c3d4e").unwrap();
+
+        for key in [0, 1, 42, u64::MAX] {
+            let anonymized = v.anonymize(key);
+
+            assert_ne!(anonymized, v);
+            assert_eq!(anonymized, v.anonymize(key));
+            assert_eq!(anonymized.deanonymize(key), v);
+        }
+        assert_ne!(v.anonymize(1), v.anonymize(2));
+    }
+
+    #[test]
+    fn test_const_integer_constructors() {
+        const V: VolumeId64 = VolumeId64::from_u64(0xa1a2a3a4a5a6a7a8);
+        const V_BE: VolumeId64 = VolumeId64::from_u64_be(0xa8a7a6a5a4a3a2a1);
+        const PAIR: VolumeId64 = VolumeId64::from_u32_pair(0xa1a2a3a4, 0xa5a6a7a8);
+        const PAIR_BE: VolumeId64 = VolumeId64::from_u32_pair_be(0xa8a7a6a5, 0xa4a3a2a1);
+
+        assert_eq!(V, V_BE);
+        assert_eq!(V, PAIR);
+        assert_eq!(V, PAIR_BE);
+        assert_eq!(
+            V.as_bytes(),
+            &[0xa8, 0xa7, 0xa6, 0xa5, 0xa4, 0xa3, 0xa2, 0xa1]
+        );
+    }
+
+    #[test]
+    fn test_const_accessors() {
+        const V: VolumeId64 =
+            VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+        const _: () = assert!(
+            V.as_u64() == 0xa8a7a6a5a4a3a2a1
+                && V.display_value() == 0xa1a2a3a4a5a6a7a8
+                && V.byte(0) == 0xa1
+                && V.rotate_bytes(1).display_value() == 0xa2a3a4a5a6a7a8a1
+                && V.numeric_distance(&VolumeId64::nil()) == 0xa8a7a6a5a4a3a2a1
+                && V.derive(V).as_u64() == 0
+                && V.min_bytes(VolumeId64::max()).as_u64() == V.as_u64()
+        );
+    }
+
+    #[test]
+    fn test_from_ntfs_sector() {
+        let mut bs = [0u8; 512];
+        bs[0x48..0x50].copy_from_slice(&[0x38, 0xfd, 0xc2, 0xfd, 0xda, 0xf6, 0xdc, 0x6d]);
+
+        assert_eq!(
+            VolumeId64::from_ntfs_sector(&bs),
+            VolumeId64::from_u64(0x6ddcf6dafdc2fd38)
+        );
+        assert_eq!(VolumeId64::from_ntfs_sector(&[0u8; 512]), VolumeId64::nil());
+    }
+
+    #[test]
+    fn test_matches_disk_field() {
+        let v = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+
+        assert!(v.matches_disk_field([0x6d, 0xdc, 0xf6, 0xda, 0xfd, 0xc2, 0xfd, 0x38]));
+        assert!(v.matches_disk_field([0x38, 0xfd, 0xc2, 0xfd, 0xda, 0xf6, 0xdc, 0x6d]));
+        assert!(!v.matches_disk_field([0; 8]));
+        assert!(!v.matches_disk_field(v.rotate_bytes(1).into_bytes()));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_new_random_distinct() {
+        use rand_core::{RngCore, SeedableRng};
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::seed_from_u64(42);
+        let existing = [
+            VolumeId64::from_u64(rng.next_u64()),
+            VolumeId64::from_u64(rng.next_u64()),
+        ];
+
+        let mut rng = XorShiftRng::seed_from_u64(42);
+        let v = VolumeId64::new_random_distinct(&existing, &mut rng).unwrap();
+        assert!(!existing.contains(&v));
+
+        let mut expected = XorShiftRng::seed_from_u64(42);
+        expected.next_u64();
+        expected.next_u64();
+        assert_eq!(v, VolumeId64::from_u64(expected.next_u64()));
+
+        struct Constant;
+
+        impl RngCore for Constant {
+            fn next_u32(&mut self) -> u32 {
+                0
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                0
+            }
+
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                dst.fill(0);
+            }
+        }
+
+        assert_eq!(
+            VolumeId64::new_random_distinct(&[VolumeId64::nil()], &mut Constant),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bucket() {
+        let v = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+
+        assert_eq!(v.bucket(1), 0);
+        assert_eq!(v.bucket(16), v.as_bytes()[0] as u64 % 16);
+        assert_eq!(v.bucket(1000), v.as_u64() % 1000);
+        assert_eq!(v.bucket(u64::MAX), v.as_u64() % u64::MAX);
+        assert_eq!(v.bucket(0), 0);
+    }
+
+    #[test]
+    fn test_const_from_slice() {
+        const OK: Result<VolumeId64, Error> =
+            VolumeId64::from_slice_const(&[0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+        const SHORT: Result<VolumeId64, Error> = VolumeId64::from_slice_const(&[0xa1, 0xa2, 0xa3]);
+
+        assert_eq!(
+            OK,
+            VolumeId64::from_slice(&[0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8])
+        );
+        assert_eq!(SHORT, Err(Error(ErrorKind::ParseByteLength { len: 3 })));
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(VolumeId64::nil().crc32(), 0x6522df69);
+        assert_eq!(VolumeId64::from_bytes(*b"12345678").crc32(), 0x9ae0daaf);
+        assert_eq!(VolumeId64::max().crc32(), 0x2144df1c);
+    }
+
+    #[test]
+    fn test_const_volume_id64() {
+        const VOLUME_ID: VolumeId64 = const_volume_id64!("a1a2a3a4a5a6a7a8");
+
+        assert_eq!(VOLUME_ID, VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap());
+        assert_eq!(const_volume_id64!("A1A2A3A4A5A6A7A8"), VOLUME_ID);
+    }
+
+    #[test]
+    fn test_copy_into() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        let mut dst = [0xffu8; 9];
+        assert_eq!(v.copy_into(&mut dst), Ok(()));
+        assert_eq!(&dst[..8], v.as_bytes());
+        assert_eq!(dst[8], 0xff);
+
+        assert_eq!(v.copy_into_le(&mut dst), Ok(()));
+        assert_eq!(&dst[..8], &v.to_bytes_be());
+        assert_eq!(dst[8], 0xff);
+
+        let mut short = [0u8; 7];
+        let expected = Err(Error(ErrorKind::BufferTooSmall { len: 7, needed: 8 }));
+        assert_eq!(v.copy_into(&mut short), expected);
+        assert_eq!(v.copy_into_le(&mut short), expected);
+        assert_eq!(short, [0u8; 7]);
+    }
+
+    #[test]
+    fn test_octets() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(v.octets(), [161, 162, 163, 164, 165, 166, 167, 168]);
+        assert_eq!(VolumeId64::from_octets(v.octets()), v);
+    }
+
+    #[test]
+    fn test_from_field() {
+        use crate::Endianness;
+
+        let bytes = [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8];
+
+        assert_eq!(
+            VolumeId64::from_field(bytes, Endianness::Little),
+            VolumeId64::from_bytes(bytes)
+        );
+        assert_eq!(
+            VolumeId64::from_field(bytes, Endianness::Big),
+            VolumeId64::from_bytes_be(bytes)
+        );
+        assert_eq!(
+            VolumeId64::from_field(bytes, Endianness::Big).display_value(),
+            VolumeId64::from_field(bytes, Endianness::Little).as_u64()
+        );
+    }
+
+    #[test]
+    fn test_xor_all() {
+        let a = VolumeId64::from_u64(0x6ddcf6dafdc2fd38);
+        let b = VolumeId64::from_u64(0xa1a2a3a4a5a6a7a8);
+        let c = VolumeId64::from_u64(0x0000ffff0000ffff);
+
+        let combined = VolumeId64::xor_all([a, b, c]);
+        assert_eq!(
+            combined,
+            VolumeId64::from_u64(0x6ddcf6dafdc2fd38 ^ 0xa1a2a3a4a5a6a7a8 ^ 0x0000ffff0000ffff)
+        );
+        assert_eq!(VolumeId64::xor_all([c, a, b]), combined);
+        assert_eq!(VolumeId64::xor_all([b, c, a]), combined);
+        assert_eq!(VolumeId64::xor_all([a, a]), VolumeId64::nil());
+        assert_eq!(VolumeId64::xor_all([]), VolumeId64::nil());
+    }
+
+    #[test]
+    fn test_sum_wrapping() {
+        let a = VolumeId64::from_u64(0x6ddcf6dafdc2fd38);
+        let b = VolumeId64::from_u64(0xa1a2a3a4a5a6a7a8);
+
+        assert_eq!(
+            VolumeId64::sum_wrapping([a, b]),
+            VolumeId64::from_u64(u64::wrapping_add(0x6ddcf6dafdc2fd38, 0xa1a2a3a4a5a6a7a8))
+        );
+        assert_eq!(
+            VolumeId64::sum_wrapping([a, a]),
+            VolumeId64::from_u64(u64::wrapping_mul(0x6ddcf6dafdc2fd38, 2))
+        );
+        assert_eq!(VolumeId64::sum_wrapping([]), VolumeId64::nil());
+    }
+
+    #[cfg(feature = "id32")]
+    #[test]
+    fn test_windows_short() {
+        let mut bs = [0u8; 512];
+        bs[0x48..0x50].copy_from_slice(&[0xda, 0xf6, 0xdc, 0x6d, 0x4e, 0x3d, 0x2c, 0x1b]);
+
+        let short = VolumeId64::from_ntfs_sector(&bs).windows_short();
+
+        assert_eq!(short.as_u32(), 0x6ddcf6da);
+        assert_eq!(
+            short,
+            crate::id32::VolumeId32::from_bytes([0xda, 0xf6, 0xdc, 0x6d])
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_ref() {
+        let bytes = [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8];
+        let v = VolumeId64::from_bytes_ref(&bytes);
+
+        assert_eq!(*v, VolumeId64::from_bytes(bytes));
+        assert_eq!(v.as_bytes(), &bytes);
+        assert!(crate::std::ptr::eq(v.as_bytes(), &bytes));
+    }
+
+    #[test]
+    fn test_is_all_same_byte() {
+        assert!(VolumeId64::nil().is_all_same_byte());
+        assert!(VolumeId64::max().is_all_same_byte());
+        assert!(VolumeId64::from_bytes([0x5a; 8]).is_all_same_byte());
+        assert!(!VolumeId64::from_u64(1).is_all_same_byte());
+        assert!(!VolumeId64::from_u64(1 << 63).is_all_same_byte());
+    }
+
+    #[test]
+    fn test_count_degenerate() {
+        let ids = [
+            VolumeId64::nil(),
+            VolumeId64::from_u64(0x6ddcf6dafdc2fd38),
+            VolumeId64::max(),
+            VolumeId64::from_bytes([0x11; 8]),
+            VolumeId64::from_u64(1),
+        ];
+
+        assert_eq!(count_degenerate(&ids), 3);
+        assert_eq!(count_degenerate(&ids[1..2]), 0);
+        assert_eq!(count_degenerate(&[]), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_reader() {
+        use std::io::{Cursor, ErrorKind};
+
+        let mut cursor = Cursor::new([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0xff]);
+        assert_eq!(
+            VolumeId64::from_reader(&mut cursor).unwrap(),
+            VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8])
+        );
+        assert_eq!(cursor.position(), 8);
+
+        let mut short = Cursor::new([0u8; 7]);
+        assert_eq!(
+            VolumeId64::from_reader(&mut short).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_writer() {
+        use crate::Endianness;
+        use std::vec::Vec;
+
+        let volumeid64 = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+
+        for order in [Endianness::Little, Endianness::Big] {
+            let mut buf = Vec::new();
+            volumeid64.to_writer(&mut buf, order).unwrap();
+            assert_eq!(buf.len(), 8);
+
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&buf);
+            assert_eq!(VolumeId64::from_field(bytes, order), volumeid64);
+        }
+
+        let mut buf = Vec::new();
+        volumeid64.to_writer(&mut buf, Endianness::Little).unwrap();
+        assert_eq!(buf, [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+    }
+
+    #[test]
+    fn test_eq_u64() {
+        const VOLUMEID64: VolumeId64 =
+            VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+        const _: () = assert!(VOLUMEID64.eq_u64(0xa8a7a6a5a4a3a2a1));
+        const _: () = assert!(!VOLUMEID64.eq_u64(VOLUMEID64.display_value()));
+
+        assert!(VolumeId64::nil().eq_u64(0));
+        assert!(VolumeId64::max().eq_u64(u64::MAX));
+    }
+
+    #[test]
+    fn test_diff_positions() {
+        let a = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+        let b = VolumeId64::from_bytes([0xa1, 0xff, 0xa3, 0xa4, 0xa5, 0xa6, 0x00, 0xa8]);
+
+        assert!(a.diff_positions(&b).eq([1, 6]));
+        assert!(b.diff_positions(&a).eq([1, 6]));
+        assert_eq!(a.diff_positions(&a).next(), None);
+
+        // The iterator doesn't borrow either VolumeId64.
+        let positions = VolumeId64::nil().diff_positions(&VolumeId64::max());
+        assert_eq!(positions.count(), 8);
+    }
+
+    #[test]
+    fn test_distinct_byte_count() {
+        assert_eq!(
+            VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8])
+                .distinct_byte_count(),
+            8
+        );
+        assert_eq!(
+            VolumeId64::from_bytes([0xaa, 0xaa, 0xbb, 0xaa, 0xcc, 0xaa, 0xbb, 0xaa])
+                .distinct_byte_count(),
+            3
+        );
+        assert_eq!(VolumeId64::nil().distinct_byte_count(), 1);
+        assert_eq!(VolumeId64::max().distinct_byte_count(), 1);
+        assert!(VolumeId64::from_bytes([0xaa; 8]).is_all_same_byte());
+    }
+
+    #[test]
+    fn test_display_value() {
+        let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(volumeid64.display_value(), 0xa1a2a3a4a5a6a7a8);
+        assert_eq!(volumeid64.display_value(), volumeid64.as_u64().swap_bytes());
+
+        assert_eq!(volumeid64.as_u64_be(), volumeid64.display_value());
+    }
+
+    #[test]
+    fn test_redact_nibbles() {
+        let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(volumeid64.redact_nibbles(0), volumeid64);
+        assert_eq!(volumeid64.redact_nibbles(u16::MAX), VolumeId64::nil());
+        assert_eq!(
+            volumeid64.redact_nibbles(0x00ff),
+            VolumeId64::parse("00000000a5a6a7a8").unwrap()
+        );
+        assert_eq!(
+            volumeid64.redact_nibbles(0b1000_0000_0000_0001),
+            VolumeId64::parse("01a2a3a4a5a6a7a0").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_content_hash() {
+        let data = [0x55u8, 0xaa, 0x00, 0xff];
+
+        assert_eq!(
+            VolumeId64::from_content_hash(&data),
+            VolumeId64::from_content_hash(&data)
+        );
+        assert_ne!(
+            VolumeId64::from_content_hash(&data),
+            VolumeId64::from_content_hash(&data[..3])
+        );
+        assert_eq!(
+            VolumeId64::from_content_hash(&[]).as_u64(),
+            0xcbf29ce484222325
+        );
+        assert_eq!(
+            VolumeId64::from_content_hash(b"backup-disk"),
+            VolumeId64::from_label("backup-disk")
+        );
+    }
+
+    #[test]
+    fn test_with_parity() {
+        let volumeid64 = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+        let bytes = volumeid64.with_parity();
+
+        assert_eq!(
+            bytes,
+            [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0x08]
+        );
+        assert_eq!(VolumeId64::from_parity_checked(&bytes), Ok(volumeid64));
+        assert_eq!(VolumeId64::nil().with_parity(), [0; 9]);
+
+        let mut corrupted = bytes;
+        corrupted[2] ^= 0x01;
+        assert_eq!(
+            VolumeId64::from_parity_checked(&corrupted),
+            Err(Error(ErrorKind::ParseParity {
+                expected: 0x08 ^ 0x01,
+                found: 0x08,
+            }))
+        );
+
+        let mut corrupted = bytes;
+        corrupted[8] = 0xff;
+        assert!(VolumeId64::from_parity_checked(&corrupted).is_err());
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert!(volumeid64.matches_pattern("a1a2a3a4a5a6a7a8"));
+        assert!(volumeid64.matches_pattern("a1??a3a4a5a6a7a8"));
+        assert!(volumeid64.matches_pattern("A1A2????????????"));
+        assert!(volumeid64.matches_pattern("????????????????"));
+
+        assert!(!volumeid64.matches_pattern("b1??????????????"));
+        assert!(!volumeid64.matches_pattern("gggggggggggggggg"));
+
+        assert!(!volumeid64.matches_pattern("1??a3a4a5a6a7a8"));
+        assert!(!volumeid64.matches_pattern("a1??a3a4a5a6a7a8?"));
+        assert!(!volumeid64.matches_pattern(""));
+    }
+
+    #[test]
+    fn test_eq_byteswapped() {
+        let a = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+
+        assert!(a.eq_byteswapped(&VolumeId64::from_bytes([
+            0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8
+        ])));
+        assert!(a.eq_byteswapped(&VolumeId64::from_bytes([
+            0xa8, 0xa7, 0xa6, 0xa5, 0xa4, 0xa3, 0xa2, 0xa1
+        ])));
+        assert!(
+            VolumeId64::from_bytes([0xa8, 0xa7, 0xa6, 0xa5, 0xa4, 0xa3, 0xa2, 0xa1])
+                .eq_byteswapped(&a)
+        );
+        assert!(a.eq_byteswapped(&VolumeId64::from_bytes_be(*a.as_bytes())));
+
+        assert!(!a.eq_byteswapped(&VolumeId64::from_bytes([
+            0xa5, 0xa6, 0xa7, 0xa8, 0xa1, 0xa2, 0xa3, 0xa4
+        ])));
+        assert!(!a.eq_byteswapped(&VolumeId64::max()));
+    }
+
+    #[test]
+    fn test_to_rgb() {
+        let volumeid64 = VolumeId64::from_bytes([0x10, 0x20, 0x30, 0x01, 0x02, 0x03, 0x40, 0x50]);
+
+        assert_eq!(volumeid64.to_rgb(), (0x51, 0x72, 0x33));
+        assert_eq!(volumeid64.to_rgb(), volumeid64.to_rgb());
+        assert_eq!(
+            VolumeId64::from_label("disk").to_rgb(),
+            VolumeId64::from_label("disk").to_rgb()
+        );
+        assert_eq!(VolumeId64::nil().to_rgb(), (0, 0, 0));
+        assert_ne!(VolumeId64::from_u64(1).to_rgb(), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_nil_max_str() {
+        use std::string::ToString;
+
+        assert_eq!(VolumeId64::nil().to_string(), VolumeId64::NIL_STR);
+        assert_eq!(VolumeId64::max().to_string(), VolumeId64::MAX_STR);
+        assert_eq!(&*VolumeId64::nil().simple_str(), VolumeId64::NIL_STR);
+        assert_eq!(
+            VolumeId64::parse(VolumeId64::NIL_STR),
+            Ok(VolumeId64::nil())
+        );
+    }
+
+    #[test]
+    fn test_as_i64() {
+        let volumeid64 = VolumeId64::from_u64(0xa1a2a3a4a5a6a7a8);
+
+        assert_eq!(volumeid64.as_i64(), -6799692559826901080);
+        assert_eq!(VolumeId64::from_i64(-6799692559826901080), volumeid64);
+        assert_eq!(VolumeId64::from_u64(42).as_i64(), 42);
+        assert_eq!(VolumeId64::nil().as_i64(), 0);
+        assert_eq!(VolumeId64::max().as_i64(), -1);
+        assert_eq!(VolumeId64::from_i64(i64::MIN).as_u64(), 1 << 63);
+    }
+
+    #[test]
+    fn test_in_range() {
+        let low = VolumeId64::from_u64(0x0000_0000_0000_00ff);
+        let high = VolumeId64::from_u64(0x0000_0000_00ff_0000);
+
+        assert!(VolumeId64::from_u64(0x0000_0000_0000_ff00).in_range(low, high));
+        assert!(low.in_range(low, high));
+        assert!(high.in_range(low, high));
+        assert!(low.in_range(low, low));
+
+        assert!(!VolumeId64::nil().in_range(low, high));
+        assert!(!VolumeId64::max().in_range(low, high));
+        assert!(!VolumeId64::from_u64(0x0000_0000_0000_ff00).in_range(high, low));
+
+        // The stored bytes of low sort after those of the middle value, but
+        // the range uses the numeric value.
+        assert!(low > VolumeId64::from_u64(0x0000_0000_0000_ff00));
     }
 }
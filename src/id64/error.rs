@@ -1,4 +1,7 @@
-use crate::std::{error, fmt, str::from_utf8};
+use crate::{
+    common::HexError,
+    std::{error, fmt, str::from_utf8},
+};
 
 /// A general error that can occur when working with VolumeId64s.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -16,6 +19,36 @@ pub(crate) enum ErrorKind {
     ParseSimpleLength { len: usize },
     /// A byte array didn't contain 8 bytes
     ParseByteLength { len: usize },
+    /// Invalid character in an alternative encoding of a [`VolumeId64`]
+    ///
+    /// [`VolumeId64`]: ../struct.VolumeId64.html
+    ParseEncodedChar {
+        character: char,
+        index: usize,
+        expected: &'static str,
+    },
+    /// An alternative encoding of a [`VolumeId64`] had the wrong length
+    ///
+    /// [`VolumeId64`]: ../struct.VolumeId64.html
+    ParseEncodedLength { len: usize, expected: usize },
+    /// The decoded value doesn't fit in a [`VolumeId64`]
+    ///
+    /// [`VolumeId64`]: ../struct.VolumeId64.html
+    ParseOutOfRange,
+    /// The input didn't start with the expected prefix
+    ParsePrefix { expected: &'static str },
+    /// A hexdump line didn't have enough bytes
+    HexdumpLength { len: usize, expected: usize },
+    /// The input had a mismatched or nested wrapper
+    ParseWrapper,
+    /// A parity byte didn't match the bytes it protects
+    ParseParity { expected: u8, found: u8 },
+    /// The input didn't contain the expected delimiter
+    ParseMissingDelimiter { delimiter: char },
+    /// The key of a `key=value` pair didn't match the expected key
+    ParseKey,
+    /// A destination buffer was too small to hold the bytes
+    BufferTooSmall { len: usize, needed: usize },
     /// The input was not a valid UTF8 string
     ParseInvalidUTF8,
 }
@@ -63,6 +96,24 @@ impl<'a> InvalidVolumeId64<'a> {
             len: input_str.len(),
         });
     }
+
+    /// Converts the lightweight error type into detailed diagnostics, using the
+    /// problem that [`try_parse_hex_detailed`] found in simple format input.
+    ///
+    /// [`try_parse_hex_detailed`]: crate::try_parse_hex_detailed
+    pub(crate) fn into_err_with_hex(self, err: HexError) -> Error {
+        match err.byte {
+            Some(byte) if !byte.is_ascii_hexdigit() => match from_utf8(self.0) {
+                // Every byte before the problem is a hex digit, so it starts a char
+                Ok(s) => Error(ErrorKind::ParseChar {
+                    character: s[err.index - 1..].chars().next().unwrap_or(byte as char),
+                    index: err.index,
+                }),
+                Err(_) => Error(ErrorKind::ParseInvalidUTF8),
+            },
+            _ => Error(ErrorKind::ParseSimpleLength { len: self.0.len() }),
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -87,6 +138,54 @@ impl fmt::Display for Error {
             ErrorKind::ParseByteLength { len } => {
                 write!(f, "invalid byte length, found {}", len)
             }
+            ErrorKind::ParseEncodedChar {
+                character,
+                index,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "invalid character: expected {}, found `{}` at {}",
+                    expected, character, index
+                )
+            }
+            ErrorKind::ParseEncodedLength { len, expected } => {
+                write!(f, "invalid length: expected {}, found {}", expected, len)
+            }
+            ErrorKind::ParseOutOfRange => write!(f, "invalid value: out of range"),
+            ErrorKind::ParsePrefix { expected } => {
+                write!(f, "invalid prefix: expected `{}`", expected)
+            }
+            ErrorKind::HexdumpLength { len, expected } => {
+                write!(
+                    f,
+                    "invalid hexdump line: expected at least {} bytes, found {}",
+                    expected, len
+                )
+            }
+            ErrorKind::ParseWrapper => {
+                write!(f, "invalid wrapper: expected a single matching pair")
+            }
+            ErrorKind::ParseParity { expected, found } => {
+                write!(
+                    f,
+                    "invalid parity: expected {:#04x}, found {:#04x}",
+                    expected, found
+                )
+            }
+            ErrorKind::ParseMissingDelimiter { delimiter } => {
+                write!(f, "missing delimiter: expected `{}`", delimiter)
+            }
+            ErrorKind::ParseKey => {
+                write!(f, "invalid key: didn't match the expected key")
+            }
+            ErrorKind::BufferTooSmall { len, needed } => {
+                write!(
+                    f,
+                    "buffer too small: expected at least {} bytes, found {}",
+                    needed, len
+                )
+            }
             ErrorKind::ParseInvalidUTF8 => write!(f, "non-UTF8 input"),
         }
     }
@@ -13,17 +13,22 @@
 //! Adapters for alternative string formats.
 
 use crate::{
-    common::{LOWER, UPPER},
+    common::{
+        FormatKind, IdStr, LOWER, UPPER, base64url_encode, eq_ignore_ascii_case, write_separated,
+    },
     id64::{VolumeId64, error::Error},
     std::{borrow::Borrow, fmt, hash::Hash, mem::transmute, str::FromStr},
 };
 
 #[cfg(feature = "alloc")]
-use crate::alloc::string::{String, ToString};
+use crate::alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
 
 impl fmt::Debug for VolumeId64 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        return fmt::LowerHex::fmt(&self, f);
+        return write!(f, "VolumeId64({:x})", self);
     }
 }
 
@@ -74,6 +79,21 @@ impl fmt::UpperHex for VolumeId64 {
 #[repr(transparent)]
 pub struct SimpleId64(VolumeId64);
 
+/// Format a [`VolumeId64`] using a [`FormatKind`] chosen at runtime.
+///
+/// Returned by [`VolumeId64::display_as`].
+///
+/// [`FormatKind`]: crate::FormatKind
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Formatted<'a>(&'a VolumeId64, FormatKind, bool);
+
+/// Format a [`VolumeId64`] as dotted decimal octets, like
+/// `161.162.163.164.165.166.167.168`.
+///
+/// Returned by [`VolumeId64::dotted`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Octets(VolumeId64);
+
 impl VolumeId64 {
     /// Get a [`SimpleId64`] formatter.
     #[inline]
@@ -83,9 +103,503 @@ impl VolumeId64 {
 
     /// Get a borrowed [`SimpleId64`] formatter.
     #[inline]
-    pub fn as_simple(&self) -> &SimpleId64 {
+    pub const fn as_simple(&self) -> &SimpleId64 {
         unsafe { transmute(self) }
     }
+
+    /// Returns the VolumeId64 as a lower-case simple string, stored inline
+    /// without needing an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    ///
+    /// assert_eq!(&*volumeid64.simple_str(), "6ddcf6dafdc2fd38");
+    /// ```
+    #[inline]
+    pub const fn simple_str(&self) -> IdStr<{ SimpleId64::LENGTH }> {
+        IdStr::new(format_simpleid64(&self.0, false), SimpleId64::LENGTH)
+    }
+
+    /// Writes the VolumeId64 as an upper-case simple string to `buffer`, and
+    /// returns the subslice of the buffer that contains it.
+    ///
+    /// This is the same as `self.simple().encode_upper(buffer)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough: it must have length at least
+    /// [`SimpleId64::LENGTH`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    /// let mut buf = [0u8; 16];
+    ///
+    /// assert_eq!(volumeid64.encode_upper(&mut buf), "6DDCF6DAFDC2FD38");
+    /// ```
+    ///
+    /// [`SimpleId64::LENGTH`]: SimpleId64::LENGTH
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        self.as_simple().encode_upper(buffer)
+    }
+
+    /// Get a [`Formatted`] adapter that displays the VolumeId64 using `kind`,
+    /// with upper-case hex digits if `upper` is set.
+    ///
+    /// This is useful when the format is only known at runtime, for example
+    /// when it is picked by a command line flag.
+    /// [`FormatKind::Auto`] uses the simple format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::{FormatKind, id64::VolumeId64};
+    /// let volumeid64 = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid64.display_as(FormatKind::Hyphenated, true).to_string(),
+    ///     "6DDC-F6DA-FDC2-FD38",
+    /// );
+    /// assert_eq!(
+    ///     volumeid64.display_as(FormatKind::Colon, false).to_string(),
+    ///     "6d:dc:f6:da:fd:c2:fd:38",
+    /// );
+    /// ```
+    ///
+    /// [`FormatKind::Auto`]: crate::FormatKind::Auto
+    #[inline]
+    pub const fn display_as(&self, kind: FormatKind, upper: bool) -> Formatted<'_> {
+        Formatted(self, kind, upper)
+    }
+
+    /// Get an [`Octets`] formatter that displays the VolumeId64 as dotted
+    /// decimal octets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.dotted().to_string(), "161.162.163.164.165.166.167.168");
+    /// ```
+    #[inline]
+    pub const fn dotted(self) -> Octets {
+        Octets(self)
+    }
+
+    /// Writes the VolumeId64 as hex digits to `w`, inserting `sep` after every
+    /// `group` digits, with upper-case hex digits if `upper` is set.
+    ///
+    /// A `group` of 0, or one that covers all 16 digits, writes the simple
+    /// format with no separators. This doesn't need an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    /// let mut s = String::new();
+    ///
+    /// volumeid64.write_grouped(&mut s, 4, '-', true).unwrap();
+    ///
+    /// assert_eq!(s, "6DDC-F6DA-FDC2-FD38");
+    /// ```
+    pub fn write_grouped<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        group: usize,
+        sep: char,
+        upper: bool,
+    ) -> fmt::Result {
+        let hex = format_simpleid64(&self.0, upper);
+
+        for (i, &c) in hex.iter().enumerate() {
+            if group > 0 && i > 0 && i % group == 0 {
+                w.write_char(sep)?;
+            }
+            w.write_char(c as char)?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl VolumeId64 {
+    /// Returns a filename for the VolumeId64, like `fat_a1a2a3a4a5a6a7a8.img`, for
+    /// naming disk image dumps.
+    ///
+    /// The serial is written in lower-case simple form. Any character of
+    /// `prefix` or `ext` that isn't an ASCII letter, digit, `-` or `_` is
+    /// replaced with `_`, so the name is safe on every common filesystem. An
+    /// empty `prefix` or `ext` is left out along with its separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("A1A2A3A4A5A6A7A8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.to_filename("fat", "img"), "fat_a1a2a3a4a5a6a7a8.img");
+    /// assert_eq!(volumeid64.to_filename("", ".bin"), "a1a2a3a4a5a6a7a8.bin");
+    /// ```
+    pub fn to_filename(&self, prefix: &str, ext: &str) -> String {
+        crate::common::filename(prefix, &self.simple_str(), ext)
+    }
+
+    /// Parses `input` and returns it in the canonical lower-case simple form.
+    ///
+    /// If `input` is already canonical it is returned as [`Cow::Borrowed`]
+    /// without allocating, otherwise a new string is returned as
+    /// [`Cow::Owned`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` can't be parsed with
+    /// [`parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// # use std::borrow::Cow;
+    /// assert!(matches!(
+    ///     VolumeId64::normalize_cow("6ddcf6dafdc2fd38").unwrap(),
+    ///     Cow::Borrowed("6ddcf6dafdc2fd38"),
+    /// ));
+    /// assert_eq!(
+    ///     VolumeId64::normalize_cow("6DDCF6DAFDC2FD38").unwrap(),
+    ///     Cow::<str>::Owned("6ddcf6dafdc2fd38".into()),
+    /// );
+    /// ```
+    ///
+    /// [`parse`]: ../struct.VolumeId64.html#method.parse
+    pub fn normalize_cow(input: &str) -> Result<Cow<'_, str>, Error> {
+        let volumeid64 = VolumeId64::parse(input)?;
+
+        if input.as_bytes() == format_simpleid64(&volumeid64.0, false) {
+            return Ok(Cow::Borrowed(input));
+        }
+
+        Ok(Cow::Owned(volumeid64.to_string()))
+    }
+
+    /// Returns the [`as_u64`] value of the VolumeId64 as a lower-case base-36
+    /// string.
+    ///
+    /// The result is left-padded with zeros to 13 digits, which is enough to
+    /// hold any 64bit value, so it always has the same length. It can be
+    /// parsed back with [`from_base36_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// assert_eq!(VolumeId64::from_u64(35).to_base36(), "000000000000z");
+    /// assert_eq!(VolumeId64::max().to_base36(), "3w5e11264sgsf");
+    /// ```
+    ///
+    /// [`as_u64`]: ../struct.VolumeId64.html#method.as_u64
+    /// [`from_base36_str`]: ../struct.VolumeId64.html#method.from_base36_str
+    pub fn to_base36(&self) -> String {
+        let mut buf = ['0'; 13];
+        let mut value = self.as_u64();
+        let mut i = buf.len();
+
+        while value > 0 {
+            i -= 1;
+            buf[i] = char::from_digit((value % 36) as u32, 36).unwrap();
+            value /= 36;
+        }
+
+        buf.iter().collect()
+    }
+
+    /// Returns the VolumeId64 as a lower-case hex string with `sep` inserted
+    /// after every `group` hex digits.
+    ///
+    /// A `group` of 0, or one that covers all 16 digits, gives the simple
+    /// format with no separators. See [`write_grouped`] for a version that
+    /// doesn't need an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    ///
+    /// assert_eq!(volumeid64.grouped(4, '-'), "6ddc-f6da-fdc2-fd38");
+    /// assert_eq!(volumeid64.grouped(2, ':'), "6d:dc:f6:da:fd:c2:fd:38");
+    /// assert_eq!(volumeid64.grouped(0, '-'), "6ddcf6dafdc2fd38");
+    /// ```
+    ///
+    /// [`write_grouped`]: ../struct.VolumeId64.html#method.write_grouped
+    pub fn grouped(&self, group: usize, sep: char) -> String {
+        let mut s = String::with_capacity(SimpleId64::LENGTH * 2);
+        self.write_grouped(&mut s, group, sep, false).unwrap();
+        s
+    }
+
+    /// Returns the 64 bits of the VolumeId64 as a string of `0` and `1`
+    /// characters, for rendering the serial as a bit pattern.
+    ///
+    /// See [`encode_bits`] for the bit order, and for a version that doesn't
+    /// need an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid64.to_bit_string(),
+    ///     "1010000110100010101000111010010010100101101001101010011110101000",
+    /// );
+    /// ```
+    ///
+    /// [`encode_bits`]: #method.encode_bits
+    pub fn to_bit_string(&self) -> String {
+        let mut buf = [0u8; 64];
+        self.encode_bits(&mut buf).to_string()
+    }
+
+    /// Returns the VolumeId64 as an upper-case simple string.
+    ///
+    /// This is the same as `format!("{:X}", self)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    ///
+    /// assert_eq!(volumeid64.to_uppercase_string(), "6DDCF6DAFDC2FD38");
+    /// ```
+    pub fn to_uppercase_string(&self) -> String {
+        let mut buf = [0u8; SimpleId64::LENGTH];
+        self.encode_upper(&mut buf).to_string()
+    }
+
+    /// Returns the VolumeId64 as a lower-case simple string together with its
+    /// stored bytes, for logging both forms from one value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// let (s, bytes) = volumeid64.dump();
+    ///
+    /// assert_eq!(s, "a1a2a3a4a5a6a7a8");
+    /// assert_eq!(bytes, [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+    /// ```
+    pub fn dump(&self) -> (String, [u8; 8]) {
+        (self.simple_str().to_string(), self.0)
+    }
+
+    /// Returns [`as_u64`] as a `0x` prefixed hex literal, for generating C
+    /// or Rust source code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.to_c_literal(), "0xa8a7a6a5a4a3a2a1");
+    /// ```
+    ///
+    /// [`as_u64`]: ../struct.VolumeId64.html#method.as_u64
+    pub fn to_c_literal(&self) -> String {
+        use fmt::Write;
+
+        let mut s = String::with_capacity(18);
+        write!(s, "{:#018x}", self.as_u64()).unwrap();
+
+        s
+    }
+
+    /// Returns the stored bytes as a Rust array literal, for generating Rust
+    /// source code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(
+    ///     volumeid64.to_rust_bytes_literal(),
+    ///     "[0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]",
+    /// );
+    /// ```
+    pub fn to_rust_bytes_literal(&self) -> String {
+        use fmt::Write;
+
+        let mut s = String::with_capacity(8 * 6);
+
+        s.push('[');
+        for (i, byte) in self.0.iter().enumerate() {
+            if i > 0 {
+                s.push_str(", ");
+            }
+            write!(s, "{:#04x}", byte).unwrap();
+        }
+        s.push(']');
+
+        s
+    }
+
+    /// Returns the stored bytes in reverse order, as a lower-case hex string.
+    ///
+    /// This is the hex of [`to_bytes_be`], which is also the [`as_u64`] value
+    /// shown by Windows, and is the reverse of [`to_string`], which shows the
+    /// bytes in the order the VolumeId64 stores them. It is useful when a raw
+    /// dump of the field was written out as a little-endian integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.to_stored_hex_string(), "a8a7a6a5a4a3a2a1");
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    ///
+    /// [`to_bytes_be`]: ../struct.VolumeId64.html#method.to_bytes_be
+    /// [`as_u64`]: ../struct.VolumeId64.html#method.as_u64
+    /// [`to_string`]: crate::alloc::string::ToString::to_string
+    pub fn to_stored_hex_string(&self) -> String {
+        VolumeId64::from_bytes(self.to_bytes_be())
+            .simple_str()
+            .to_string()
+    }
+}
+
+impl VolumeId64 {
+    /// Writes the 64 bits of the VolumeId64 to `buffer` as `0` and `1`
+    /// characters, and returns the buffer as a string.
+    ///
+    /// The bits are written most significant first, with the bytes in the
+    /// order they are stored, so each group of four characters matches one
+    /// hex digit of the [`Display`] format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    /// let mut buf = [0u8; 64];
+    ///
+    /// assert_eq!(
+    ///     volumeid64.encode_bits(&mut buf),
+    ///     "1010000110100010101000111010010010100101101001101010011110101000",
+    /// );
+    /// ```
+    ///
+    /// [`Display`]: core::fmt::Display
+    pub fn encode_bits<'buf>(&self, buffer: &'buf mut [u8; 64]) -> &'buf mut str {
+        for (i, bit) in buffer.iter_mut().enumerate() {
+            *bit = if self.0[i / 8] & (0x80 >> (i % 8)) != 0 {
+                b'1'
+            } else {
+                b'0'
+            };
+        }
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buffer) }
+    }
+}
+
+/// The prefix of a [`VolumeId64`] log token.
+pub(crate) const LOG_TOKEN_PREFIX: &str = "vid64:";
+
+impl VolumeId64 {
+    /// Returns the VolumeId64 as a prefixed token for logs, like
+    /// `vid64:a1a2a3a4a5a6a7a8`.
+    ///
+    /// The prefix keeps serials from being confused with other hex values
+    /// and makes them easy to find with `grep`. The token can be parsed back
+    /// with [`from_log_token`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.to_log_token(), "vid64:a1a2a3a4a5a6a7a8");
+    /// ```
+    ///
+    /// [`from_log_token`]: ../struct.VolumeId64.html#method.from_log_token
+    pub const fn to_log_token(&self) -> IdStr<22> {
+        let prefix = LOG_TOKEN_PREFIX.as_bytes();
+        let hex = format_simpleid64(&self.0, false);
+        let mut buf = [0u8; 22];
+        let mut i = 0;
+
+        while i < 22 {
+            buf[i] = if i < prefix.len() {
+                prefix[i]
+            } else {
+                hex[i - prefix.len()]
+            };
+            i += 1;
+        }
+
+        IdStr::new(buf, 22)
+    }
+
+    /// Returns the VolumeId64 as a token for QR codes, the 16 upper-case hex
+    /// digits of the simple format, like `6DDCF6DAFDC2FD38`.
+    ///
+    /// The token only contains `0`-`9` and `A`-`F`, so it always fits the QR
+    /// alphanumeric mode, which is more compact than byte mode. It can be
+    /// parsed back with [`from_qr_token`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+    ///
+    /// assert_eq!(volumeid64.to_qr_token(), "6DDCF6DAFDC2FD38");
+    /// ```
+    ///
+    /// [`from_qr_token`]: ../struct.VolumeId64.html#method.from_qr_token
+    pub const fn to_qr_token(&self) -> IdStr<{ SimpleId64::LENGTH }> {
+        IdStr::new(format_simpleid64(&self.0, true), SimpleId64::LENGTH)
+    }
+
+    /// Returns the 8 stored bytes of the VolumeId64 as 11 characters of
+    /// unpadded base64url, like `oaKjpKWmp6g` for `a1a2a3a4a5a6a7a8`.
+    ///
+    /// This is more compact than hex when embedding a serial in a URL. It can
+    /// be parsed back with [`from_base64url_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+    ///
+    /// assert_eq!(volumeid64.to_base64url(), "oaKjpKWmp6g");
+    /// ```
+    ///
+    /// [`from_base64url_str`]: ../struct.VolumeId64.html#method.from_base64url_str
+    pub const fn to_base64url(&self) -> IdStr<11> {
+        IdStr::new(base64url_encode(&self.0), 11)
+    }
 }
 
 #[inline]
@@ -193,6 +707,52 @@ impl SimpleId64 {
     pub const fn into_volumeid64(self) -> VolumeId64 {
         self.0
     }
+
+    /// Tests if `s` is this [`SimpleId64`]'s encoded form, ignoring ASCII case.
+    ///
+    /// This doesn't allocate, so it can be used to match a serial against a
+    /// byte buffer in `no_std` code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fat_volume_id::id64::VolumeId64;
+    ///
+    /// let simple = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap().simple();
+    /// assert!(simple.eq_ascii(b"6DDCF6dafdc2FD38"));
+    /// ```
+    pub const fn eq_ascii(&self, s: &[u8]) -> bool {
+        eq_ignore_ascii_case(&format_simpleid64(self.0.as_bytes(), false), s)
+    }
+
+    /// Writes the [`VolumeId64`] as a lower-case simple string to `buffer`,
+    /// and returns its first and second halves.
+    ///
+    /// This lets UIs show the two halves separately, like `6ddcf6da` and
+    /// `fdc2fd38`, without allocating for each one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fat_volume_id::id64::{VolumeId64, fmt::SimpleId64};
+    ///
+    /// let simple = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap().simple();
+    /// let mut buf = [0u8; SimpleId64::LENGTH];
+    ///
+    /// assert_eq!(simple.split_halves(&mut buf), ("6ddcf6da", "fdc2fd38"));
+    /// ```
+    ///
+    /// [`VolumeId64`]: ../struct.VolumeId64.html
+    pub fn split_halves<'buf>(
+        &self,
+        buffer: &'buf mut [u8; Self::LENGTH],
+    ) -> (&'buf str, &'buf str) {
+        *buffer = format_simpleid64(self.0.as_bytes(), false);
+        let (hi, lo) = buffer.split_at(Self::LENGTH / 2);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { (str::from_utf8_unchecked(hi), str::from_utf8_unchecked(lo)) }
+    }
 }
 
 impl fmt::Display for SimpleId64 {
@@ -264,6 +824,358 @@ impl FromStr for SimpleId64 {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         crate::id64::parser::parse_simpleid64(s.as_bytes())
             .map(|b| SimpleId64(VolumeId64(b)))
-            .map_err(|invalid| invalid.into_err())
+            .map_err(|_| crate::id64::InvalidVolumeId64(s.as_bytes()).into_err())
+    }
+}
+
+impl fmt::Display for Formatted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.1, self.2) {
+            (FormatKind::Simple | FormatKind::Auto, false) => {
+                fmt::LowerHex::fmt(self.0.as_simple(), f)
+            }
+            (FormatKind::Simple | FormatKind::Auto, true) => {
+                fmt::UpperHex::fmt(self.0.as_simple(), f)
+            }
+            (FormatKind::Hyphenated, upper) => write_separated(f, self.0.as_bytes(), 2, '-', upper),
+            (FormatKind::Colon, upper) => write_separated(f, self.0.as_bytes(), 1, ':', upper),
+        }
+    }
+}
+
+impl fmt::Display for Octets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, octet) in self.0.as_bytes().iter().enumerate() {
+            if i > 0 {
+                fmt::Write::write_char(f, '.')?;
+            }
+            write!(f, "{}", octet)?;
+        }
+        return Ok(());
+    }
+}
+
+impl From<Octets> for VolumeId64 {
+    #[inline]
+    fn from(f: Octets) -> Self {
+        f.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn test_display_as() {
+        let v = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Simple, false)),
+            "6ddcf6dafdc2fd38"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Simple, true)),
+            "6DDCF6DAFDC2FD38"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Hyphenated, false)),
+            "6ddc-f6da-fdc2-fd38"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Hyphenated, true)),
+            "6DDC-F6DA-FDC2-FD38"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Colon, false)),
+            "6d:dc:f6:da:fd:c2:fd:38"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Colon, true)),
+            "6D:DC:F6:DA:FD:C2:FD:38"
+        );
+        assert_eq!(
+            format!("{:<25}|", v.display_as(FormatKind::Colon, false)),
+            "6d:dc:f6:da:fd:c2:fd:38  |"
+        );
+        assert_eq!(
+            format!("{:*^21}", v.display_as(FormatKind::Hyphenated, false)),
+            "*6ddc-f6da-fdc2-fd38*"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Auto, false)),
+            "6ddcf6dafdc2fd38"
+        );
+        assert_eq!(
+            format!("{}", v.display_as(FormatKind::Auto, true)),
+            "6DDCF6DAFDC2FD38"
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(format!("{:?}", v), "VolumeId64(a1a2a3a4a5a6a7a8)");
+        assert_eq!(format!("{}", v), "a1a2a3a4a5a6a7a8");
+        assert_eq!(
+            format!("{:?}", VolumeId64::nil()),
+            "VolumeId64(0000000000000000)"
+        );
+    }
+
+    #[test]
+    fn test_id_str() {
+        let v = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+        let simple = v.simple_str();
+
+        assert_eq!(&*simple, "6ddcf6dafdc2fd38");
+        assert_eq!(simple, "6ddcf6dafdc2fd38");
+        assert_eq!(simple.len(), SimpleId64::LENGTH);
+        assert_eq!(format!("{}", simple), format!("{}", v));
+        assert_eq!(format!("{:?}", simple), "\"6ddcf6dafdc2fd38\"");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_grouped() {
+        let v = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+
+        assert_eq!(v.grouped(4, '-'), "6ddc-f6da-fdc2-fd38");
+        assert_eq!(v.grouped(2, ':'), "6d:dc:f6:da:fd:c2:fd:38");
+        assert_eq!(v.grouped(3, ' '), "6dd cf6 daf dc2 fd3 8");
+        assert_eq!(v.grouped(0, '-'), "6ddcf6dafdc2fd38");
+        assert_eq!(v.grouped(16, '-'), "6ddcf6dafdc2fd38");
+    }
+
+    #[test]
+    fn test_simpleid64_eq_ascii() {
+        let simple = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap().simple();
+
+        assert!(simple.eq_ascii(b"6ddcf6dafdc2fd38"));
+        assert!(simple.eq_ascii(b"6DDCF6DAFDC2FD38"));
+        assert!(simple.eq_ascii(b"6DDCF6dafdc2FD38"));
+        assert!(!simple.eq_ascii(b"6ddc-f6da-fdc2-fd38"));
+        assert!(!simple.eq_ascii(b"6ddcf6dafdc2fd3"));
+        assert!(!simple.eq_ascii(b"6ddcf6dafdc2fd30"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_normalize_cow() {
+        use crate::alloc::borrow::Cow;
+
+        assert!(matches!(
+            VolumeId64::normalize_cow("6ddcf6dafdc2fd38"),
+            Ok(Cow::Borrowed("6ddcf6dafdc2fd38"))
+        ));
+
+        let owned = VolumeId64::normalize_cow("6DDCF6DAFDC2FD38").unwrap();
+        assert!(matches!(owned, Cow::Owned(_)));
+        assert_eq!(owned, "6ddcf6dafdc2fd38");
+
+        assert!(VolumeId64::normalize_cow("6ddcf6dafdc2fd3g").is_err());
+    }
+
+    #[test]
+    fn test_split_halves() {
+        let simple = VolumeId64::parse("6DDCF6DAFDC2FD38").unwrap().simple();
+        let mut buf = [0u8; SimpleId64::LENGTH];
+
+        let (hi, lo) = simple.split_halves(&mut buf);
+        assert_eq!(hi, &"6ddcf6dafdc2fd38"[..8]);
+        assert_eq!(lo, &"6ddcf6dafdc2fd38"[8..]);
+        assert_eq!(&buf, b"6ddcf6dafdc2fd38");
+    }
+
+    #[test]
+    fn test_dotted() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(format!("{}", v.dotted()), "161.162.163.164.165.166.167.168");
+        assert_eq!(format!("{}", VolumeId64::nil().dotted()), "0.0.0.0.0.0.0.0");
+        assert_eq!(VolumeId64::from(v.dotted()), v);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_filename() {
+        let v = VolumeId64::parse("A1A2A3A4A5A6A7A8").unwrap();
+
+        assert_eq!(v.to_filename("fat", "img"), "fat_a1a2a3a4a5a6a7a8.img");
+        assert_eq!(
+            v.to_filename("usb-disk", ".img"),
+            "usb-disk_a1a2a3a4a5a6a7a8.img"
+        );
+        assert_eq!(
+            v.to_filename("../my disk", "i/m g"),
+            "___my_disk_a1a2a3a4a5a6a7a8.i_m_g"
+        );
+        assert_eq!(v.to_filename("", ""), "a1a2a3a4a5a6a7a8");
+    }
+
+    #[test]
+    fn test_encode_bits() {
+        let mut buf = [0u8; 64];
+
+        assert_eq!(
+            VolumeId64::nil().encode_bits(&mut buf),
+            "0".repeat(64).as_str()
+        );
+        assert_eq!(
+            VolumeId64::max().encode_bits(&mut buf),
+            "1".repeat(64).as_str()
+        );
+        assert_eq!(
+            VolumeId64::parse("a1a2a3a4a5a6a7a8")
+                .unwrap()
+                .encode_bits(&mut buf),
+            "1010000110100010101000111010010010100101101001101010011110101000"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_bit_string() {
+        let volumeid64 = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(
+            volumeid64.to_bit_string(),
+            "1010000110100010101000111010010010100101101001101010011110101000"
+        );
+        assert_eq!(volumeid64.to_bit_string().len(), 64);
+    }
+
+    #[test]
+    fn test_encode_upper() {
+        let v = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+        let mut buf = [0u8; 17];
+
+        assert_eq!(v.encode_upper(&mut buf), "6DDCF6DAFDC2FD38");
+        assert_eq!(
+            v.encode_upper(&mut [0u8; 16]),
+            v.simple().encode_upper(&mut [0u8; 16])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_upper_small_buffer() {
+        VolumeId64::nil().encode_upper(&mut [0u8; 15]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_uppercase_string() {
+        let v = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+
+        assert_eq!(v.to_uppercase_string(), "6DDCF6DAFDC2FD38");
+        assert_eq!(v.to_uppercase_string(), format!("{:X}", v));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dump() {
+        let v = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+
+        assert_eq!(
+            v.dump(),
+            (
+                String::from("a1a2a3a4a5a6a7a8"),
+                [0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]
+            )
+        );
+        assert_eq!(v.dump().0, format!("{}", v));
+        assert_eq!(VolumeId64::parse(&v.dump().0), Ok(v));
+    }
+
+    #[test]
+    fn test_to_qr_token() {
+        const QR_ALPHANUMERIC: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+        for v in [
+            VolumeId64::nil(),
+            VolumeId64::max(),
+            VolumeId64::parse("6ddcf6dafdc2fd38").unwrap(),
+        ] {
+            let token = v.to_qr_token();
+
+            assert_eq!(token.len(), 16);
+            assert!(token.chars().all(|c| QR_ALPHANUMERIC.contains(c)));
+            assert_eq!(VolumeId64::from_qr_token(&token), Ok(v));
+        }
+    }
+
+    #[test]
+    fn test_to_base64url() {
+        assert_eq!(
+            VolumeId64::parse("a1a2a3a4a5a6a7a8")
+                .unwrap()
+                .to_base64url(),
+            "oaKjpKWmp6g"
+        );
+        assert_eq!(VolumeId64::nil().to_base64url(), "AAAAAAAAAAA");
+        assert_eq!(VolumeId64::max().to_base64url(), "__________8");
+
+        for v in [
+            VolumeId64::nil(),
+            VolumeId64::max(),
+            VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap(),
+            VolumeId64::from_label("base64url"),
+        ] {
+            assert_eq!(VolumeId64::from_base64url_str(&v.to_base64url()), Ok(v));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_write_grouped() {
+        let v = VolumeId64::parse("6ddcf6dafdc2fd38").unwrap();
+
+        let mut s = String::new();
+        v.write_grouped(&mut s, 2, ':', false).unwrap();
+        assert_eq!(s, "6d:dc:f6:da:fd:c2:fd:38");
+
+        let mut s = String::new();
+        v.write_grouped(&mut s, 4, '-', true).unwrap();
+        assert_eq!(s, "6DDC-F6DA-FDC2-FD38");
+
+        for group in [0, 16, 17] {
+            let mut s = String::new();
+            v.write_grouped(&mut s, group, '-', false).unwrap();
+            assert_eq!(s, "6ddcf6dafdc2fd38");
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_literals() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+        assert_eq!(v.to_c_literal(), "0xa8a7a6a5a4a3a2a1");
+        assert_eq!(
+            v.to_rust_bytes_literal(),
+            "[0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]"
+        );
+
+        assert_eq!(VolumeId64::nil().to_c_literal(), "0x0000000000000000");
+        assert_eq!(
+            VolumeId64::nil().to_rust_bytes_literal(),
+            "[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_stored_hex_string() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(v.to_stored_hex_string(), "a8a7a6a5a4a3a2a1");
+        assert_ne!(v.to_stored_hex_string(), v.to_string());
+        assert_eq!(v.to_stored_hex_string(), format!("{:016x}", v.as_u64()));
+
+        // A palindromic VolumeId64 reads the same either way.
+        let v = VolumeId64::from_bytes([0xab; 8]);
+        assert_eq!(v.to_stored_hex_string(), v.to_string());
     }
 }
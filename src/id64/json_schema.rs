@@ -0,0 +1,49 @@
+// Copyright 2013-2014 The Rust Project Developers.
+// Copyright 2018 The Uuid Project Developers.
+// Copyright 2025-2026 rysndavjd.
+//
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `schemars` support.
+
+use crate::{alloc::borrow::Cow, id64::VolumeId64};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+/// Describes a [`VolumeId64`] as a string of 16 hex digits, like
+/// `6ddcf6dafdc2fd38`, which is the format it is serialized in for
+/// human-readable formats.
+impl JsonSchema for VolumeId64 {
+    fn schema_name() -> Cow<'static, str> {
+        "VolumeId64".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "fat_volume_id::id64::VolumeId64".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "pattern": "^[0-9a-fA-F]{16}$"
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_schema() {
+        let schema = schemars::schema_for!(VolumeId64);
+
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("pattern").unwrap(), "^[0-9a-fA-F]{16}$");
+    }
+}
@@ -11,20 +11,20 @@
 // except according to those terms.
 
 use crate::{
-    common::{HEX_TABLE, SHL4_TABLE},
+    common::{HexError, base64url_decode, hexdump_bytes, strip_wrapper, try_parse_hex_detailed},
     id64::{
         VolumeId64,
-        error::{Error, InvalidVolumeId64},
-        fmt::SimpleId64,
+        error::{Error, ErrorKind, InvalidVolumeId64},
+        fmt::{LOG_TOKEN_PREFIX, SimpleId64},
     },
-    std::str::FromStr,
+    std::{ffi::CStr, str::FromStr},
 };
 
 impl FromStr for VolumeId64 {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::try_parse(s).map_err(|invaild| invaild.into_err())
+        Self::parse(s)
     }
 }
 
@@ -32,7 +32,7 @@ impl TryFrom<&'_ str> for VolumeId64 {
     type Error = Error;
 
     fn try_from(s: &'_ str) -> Result<Self, Self::Error> {
-        Self::try_parse(s).map_err(|invaild| invaild.into_err())
+        Self::parse(s)
     }
 }
 
@@ -41,7 +41,36 @@ impl TryFrom<crate::alloc::string::String> for VolumeId64 {
     type Error = Error;
 
     fn try_from(s: crate::alloc::string::String) -> Result<Self, Self::Error> {
-        Self::try_parse(s.as_ref()).map_err(|invaild| invaild.into_err())
+        Self::parse(&s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl VolumeId64 {
+    /// Parses a [`VolumeId64`] from an [`OsStr`] of hexadecimal digits, such
+    /// as a command line argument.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't valid UTF-8, or if
+    /// it can't be parsed as by [`parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// # use std::ffi::OsStr;
+    /// let volumeid64 = VolumeId64::parse_os(OsStr::new("49aa648a6ddcf6da"))
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "49aa648a6ddcf6da");
+    /// ```
+    /// [`OsStr`]: std::ffi::OsStr
+    /// [`parse`]: #method.parse
+    pub fn parse_os(input: &std::ffi::OsStr) -> Result<Self, Error> {
+        match input.to_str() {
+            Some(s) => Self::parse(s),
+            None => Err(Error(ErrorKind::ParseInvalidUTF8)),
+        }
     }
 }
 
@@ -105,48 +134,454 @@ impl VolumeId64 {
         match (s.len(), s) {
             (16, s) => match parse_simpleid64(s) {
                 Ok(bytes) => Ok(VolumeId64::from_bytes(bytes)),
-                Err(e) => Err(e),
+                Err(_) => Err(InvalidVolumeId64(s)),
             },
             _ => Err(InvalidVolumeId64(s)),
         }
     }
+
+    /// Parses a [`VolumeId64`] from the base-36 string produced by
+    /// [`to_base36`].
+    ///
+    /// The input must be exactly 13 base-36 digits, upper or lower-case,
+    /// encoding the [`as_u64`] value of the VolumeId64.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input has the wrong length,
+    /// contains a character that isn't a base-36 digit or encodes a value
+    /// larger than `u64::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_base36_str("000000000000z")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64, VolumeId64::from_u64(35));
+    /// ```
+    /// [`to_base36`]: #method.to_base36
+    /// [`as_u64`]: #method.as_u64
+    pub fn from_base36_str(input: &str) -> Result<Self, Error> {
+        if input.len() != 13 {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: input.len(),
+                expected: 13,
+            }));
+        }
+
+        let mut value: u64 = 0;
+        for (index, character) in input.char_indices() {
+            let digit = character
+                .to_digit(36)
+                .ok_or(Error(ErrorKind::ParseEncodedChar {
+                    character,
+                    index: index + 1,
+                    expected: "[0-9a-zA-Z]",
+                }))?;
+
+            value = value
+                .checked_mul(36)
+                .and_then(|v| v.checked_add(digit as u64))
+                .ok_or(Error(ErrorKind::ParseOutOfRange))?;
+        }
+
+        return Ok(VolumeId64::from_u64(value));
+    }
+
+    /// Parses a [`VolumeId64`] from a nul-terminated C string of hexadecimal
+    /// digits.
+    ///
+    /// Only the bytes before the nul terminator are parsed. This is useful
+    /// for serials read out of C FFI buffers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse_cstr(c"49aa648a49aa648a")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "49aa648a49aa648a");
+    /// ```
+    pub fn parse_cstr(input: &CStr) -> Result<Self, Error> {
+        Self::try_parse_ascii(input.to_bytes()).map_err(InvalidVolumeId64::into_err)
+    }
+
+    /// Parses a [`VolumeId64`] from a log token produced by [`to_log_token`],
+    /// like `vid64:a1a2a3a4a5a6a7a8`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input doesn't start with
+    /// `vid64:` or isn't followed by a simple formatted VolumeId64.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_log_token("vid64:a1a2a3a4a5a6a7a8")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    /// [`to_log_token`]: #method.to_log_token
+    pub fn from_log_token(input: &str) -> Result<Self, Error> {
+        let hex = input
+            .strip_prefix(LOG_TOKEN_PREFIX)
+            .ok_or(Error(ErrorKind::ParsePrefix {
+                expected: LOG_TOKEN_PREFIX,
+            }))?;
+
+        parse_simpleid64(hex.as_bytes())
+            .map(VolumeId64::from_bytes)
+            .map_err(|err| InvalidVolumeId64(hex.as_bytes()).into_err_with_hex(err))
+    }
+
+    /// Parses a [`VolumeId64`] from a QR code token produced by
+    /// [`to_qr_token`], like `6DDCF6DAFDC2FD38`.
+    ///
+    /// Only upper-case hex digits are accepted, since those are the characters
+    /// QR alphanumeric mode can encode.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` isn't 16 characters long
+    /// or contains a character that isn't an upper-case hex digit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_qr_token("6DDCF6DAFDC2FD38")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "6ddcf6dafdc2fd38");
+    /// assert!(VolumeId64::from_qr_token("6ddcf6dafdc2fd38").is_err());
+    /// ```
+    /// [`to_qr_token`]: #method.to_qr_token
+    pub fn from_qr_token(input: &str) -> Result<Self, Error> {
+        if input.len() != SimpleId64::LENGTH {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: input.len(),
+                expected: SimpleId64::LENGTH,
+            }));
+        }
+
+        if let Some((index, character)) = input
+            .char_indices()
+            .find(|&(_, c)| !matches!(c, '0'..='9' | 'A'..='F'))
+        {
+            return Err(Error(ErrorKind::ParseEncodedChar {
+                character,
+                index: index + 1,
+                expected: "[0-9A-F]",
+            }));
+        }
+
+        Self::parse(input)
+    }
+
+    /// Parses a [`VolumeId64`] from a line of `xxd` or `hexdump -C` output,
+    /// reading the 8 bytes at `byte_offset` from the start of the line.
+    ///
+    /// The leading offset column is skipped, and the hex columns end before
+    /// the text column, even if the text happens to look like hex: at the
+    /// double space for `xxd`, and at the `|` or after 16 bytes for
+    /// `hexdump -C`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the line doesn't have 8 bytes
+    /// at `byte_offset`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let line = "00000040: 0000 0000 a1a2 a3a4 a5a6 a7a8 0000 0000  ................";
+    /// let volumeid64 = VolumeId64::from_hexdump_fragment(line, 4)
+    ///     .expect("Failed Parsing Line");
+    ///
+    /// assert_eq!(volumeid64.as_bytes(), &[0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+    /// ```
+    pub fn from_hexdump_fragment(line: &str, byte_offset: usize) -> Result<Self, Error> {
+        hexdump_bytes::<8>(line, byte_offset)
+            .map(VolumeId64::from_bytes)
+            .map_err(|len| {
+                Error(ErrorKind::HexdumpLength {
+                    len,
+                    expected: byte_offset.saturating_add(8),
+                })
+            })
+    }
+
+    /// Parses a [`VolumeId64`] like [`parse`], after stripping a single
+    /// matching pair of `{}`, `[]`, `()`, `"` or `'` from around the input.
+    ///
+    /// This accepts serials copied from JSON or debug output. Input without a
+    /// wrapper is parsed as-is.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the wrapper is mismatched or
+    /// nested, or if the unwrapped input can't be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse_unwrapped("{a1a2a3a4a5a6a7a8}")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    /// [`parse`]: #method.parse
+    pub fn parse_unwrapped(input: &str) -> Result<Self, Error> {
+        match strip_wrapper(input) {
+            Some(inner) => Self::parse(inner),
+            None => Err(Error(ErrorKind::ParseWrapper)),
+        }
+    }
+
+    /// Parses a VolumeId64 from a `key=value` line of a config file, like
+    /// `serial = A1A2A3A4A5A6A7A8`, if its key is `key`.
+    ///
+    /// The line is split at the first `=`. Whitespace around the key and
+    /// the value is ignored, and the value is parsed as by [`parse`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `line` has no `=`, if its key
+    /// isn't `key`, or if its value can't be parsed as by [`parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse_kv("serial = A1A2A3A4A5A6A7A8", "serial")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    /// [`parse`]: #method.parse
+    pub fn parse_kv(line: &str, key: &str) -> Result<Self, Error> {
+        let (k, value) = line
+            .split_once('=')
+            .ok_or(Error(ErrorKind::ParseMissingDelimiter { delimiter: '=' }))?;
+
+        if k.trim() != key {
+            return Err(Error(ErrorKind::ParseKey));
+        }
+
+        Self::parse(value.trim())
+    }
+
+    /// Parses a VolumeId64 from an array of 16 ASCII hex digits.
+    ///
+    /// This is different from [`from_bytes`], which takes the 8 raw bytes of
+    /// the serial. Use this when the array holds the serial as text, for
+    /// example when read from a fixed-width field of a text format.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any byte isn't a hex digit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_ascii_array(b"a1a2a3a4a5a6a7a8")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    /// [`from_bytes`]: ../struct.VolumeId64.html#method.from_bytes
+    pub fn from_ascii_array(a: &[u8; 16]) -> Result<Self, Error> {
+        parse_simpleid64(a)
+            .map(VolumeId64::from_bytes)
+            .map_err(|err| InvalidVolumeId64(a).into_err_with_hex(err))
+    }
+
+    /// Parses a [`VolumeId64`] from up to 16 hexadecimal digits, treating
+    /// missing digits as leading zeros.
+    ///
+    /// Some tools print serials as integers and drop their leading zeros, so
+    /// `01a2a3a4a5a6a7a8` is shown as `1a2a3a4a5a6a7a8`. Separators aren't accepted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `input` is empty, longer than
+    /// 16 characters, or contains a character that isn't a hex digit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::parse_zero_padded("1a2a3a4a5a6a7a8")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "01a2a3a4a5a6a7a8");
+    /// ```
+    pub fn parse_zero_padded(input: &str) -> Result<Self, Error> {
+        if input.is_empty() || input.len() > SimpleId64::LENGTH {
+            return Err(Error(ErrorKind::ParseSimpleLength { len: input.len() }));
+        }
+
+        if let Some((index, character)) = input.char_indices().find(|(_, c)| !c.is_ascii_hexdigit())
+        {
+            return Err(Error(ErrorKind::ParseChar {
+                character,
+                index: index + 1,
+            }));
+        }
+
+        let mut padded = [b'0'; SimpleId64::LENGTH];
+        padded[SimpleId64::LENGTH - input.len()..].copy_from_slice(input.as_bytes());
+
+        Self::try_parse_ascii(&padded).map_err(InvalidVolumeId64::into_err)
+    }
+
+    /// Parses a [`VolumeId64`] from the 11 characters of unpadded base64url
+    /// produced by [`to_base64url`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input has the wrong length,
+    /// contains a character outside the base64url alphabet, or has bits set
+    /// past the end of the 8 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// let volumeid64 = VolumeId64::from_base64url_str("oaKjpKWmp6g")
+    ///     .expect("Failed Parsing String");
+    ///
+    /// assert_eq!(volumeid64.to_string(), "a1a2a3a4a5a6a7a8");
+    /// ```
+    /// [`to_base64url`]: #method.to_base64url
+    pub fn from_base64url_str(input: &str) -> Result<Self, Error> {
+        if input.len() != 11 {
+            return Err(Error(ErrorKind::ParseEncodedLength {
+                len: input.len(),
+                expected: 11,
+            }));
+        }
+
+        match base64url_decode(input.as_bytes()) {
+            Ok(bytes) => Ok(VolumeId64::from_bytes(bytes)),
+            Err(Some(index)) => Err(Error(ErrorKind::ParseEncodedChar {
+                character: input[index..].chars().next().unwrap_or_default(),
+                index: index + 1,
+                expected: "[A-Za-z0-9_-]",
+            })),
+            Err(None) => Err(Error(ErrorKind::ParseOutOfRange)),
+        }
+    }
+
+    /// Tests if `s` is a valid VolumeId64 string, as accepted by
+    /// [`try_parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use fat_volume_id::id64::VolumeId64;
+    /// assert!(VolumeId64::is_valid_str("6ddcf6dafdc2fd38"));
+    /// assert!(!VolumeId64::is_valid_str("6ddcf6dafdc2fd3"));
+    /// ```
+    /// [`try_parse`]: #method.try_parse
+    pub const fn is_valid_str(s: &str) -> bool {
+        Self::try_parse(s).is_ok()
+    }
 }
 
-#[inline]
-pub(crate) const fn parse_simpleid64(s: &'_ [u8]) -> Result<[u8; 8], InvalidVolumeId64<'_>> {
-    if s.len() != SimpleId64::LENGTH {
-        return Err(InvalidVolumeId64(s));
+/// An incremental parser that reads a [`VolumeId64`] one character at a time.
+///
+/// This is for interactive input, where each character can be checked as it
+/// is typed instead of parsing the whole buffer again.
+///
+/// # Examples
+/// ```
+/// # use fat_volume_id::id64::VolumeId64Parser;
+/// let mut parser = VolumeId64Parser::new();
+///
+/// for c in "6ddcf6dafdc2fd3".chars() {
+///     assert_eq!(parser.push(c), Ok(None));
+/// }
+/// assert!(parser.push('x').is_err());
+///
+/// let volumeid64 = parser.push('8').unwrap().unwrap();
+///
+/// assert_eq!(volumeid64.to_string(), "6ddcf6dafdc2fd38");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VolumeId64Parser {
+    bytes: [u8; 8],
+    digits: usize,
+}
+
+impl VolumeId64Parser {
+    /// Creates a parser with no input.
+    pub const fn new() -> Self {
+        VolumeId64Parser {
+            bytes: [0; 8],
+            digits: 0,
+        }
     }
 
-    let mut buf = [0u8; 8];
+    /// Adds the next character of input, returning the [`VolumeId64`] once
+    /// the last hex digit is added.
+    ///
+    /// A character that can't come next is rejected and not added, so the
+    /// parser is left as it was and the user can try again.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `c` isn't a hex digit, or if the
+    /// [`VolumeId64`] is already complete. The index in the error is the
+    /// position `c` would have had in the input.
+    pub fn push(&mut self, c: char) -> Result<Option<VolumeId64>, Error> {
+        let index = self.len() + 1;
 
-    let mut i = 0;
+        let nibble = match c.to_digit(16) {
+            Some(nibble) if self.digits < 16 => nibble as u8,
+            _ => {
+                return Err(Error(ErrorKind::ParseChar {
+                    character: c,
+                    index,
+                }));
+            }
+        };
 
-    while i < 8 {
-        // Convert a two-char hex value (like `A8`)
-        // into a byte (like `10101000`)
-        let h1 = HEX_TABLE[s[i * 2] as usize];
-        let h2 = HEX_TABLE[s[i * 2 + 1] as usize];
+        self.bytes[self.digits / 2] |= if self.digits.is_multiple_of(2) {
+            nibble << 4
+        } else {
+            nibble
+        };
+        self.digits += 1;
 
-        // We use `0xff` as a sentinel value to indicate
-        // an invalid hex character sequence (like the letter `G`)
-        if h1 | h2 == 0xff {
-            return Err(InvalidVolumeId64(s));
+        if self.digits == 16 {
+            return Ok(Some(VolumeId64::from_bytes(self.bytes)));
         }
 
-        // The upper nibble needs to be shifted into position
-        // to produce the final byte value
-        buf[i] = SHL4_TABLE[h1 as usize] | h2;
-        i += 1;
+        return Ok(None);
+    }
+
+    /// Returns the number of characters added so far.
+    pub const fn len(&self) -> usize {
+        self.digits
+    }
+
+    /// Tests if no characters have been added.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
-    return Ok(buf);
+    /// Removes all input, so a new [`VolumeId64`] can be parsed.
+    pub fn reset(&mut self) {
+        *self = VolumeId64Parser::new();
+    }
+}
+
+#[inline]
+pub(crate) const fn parse_simpleid64(s: &[u8]) -> Result<[u8; 8], HexError> {
+    try_parse_hex_detailed::<8>(s)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::id64::error::ErrorKind;
 
     #[test]
     fn test_parse_volumeid64_valid() {
@@ -247,4 +682,428 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn test_conversions_preserve_detailed_errors() {
+        let expected = Err(Error(ErrorKind::ParseChar {
+            character: 'x',
+            index: 16,
+        }));
+
+        assert_eq!(VolumeId64::try_from("6ddcf6da6ddcf6dx"), expected);
+        assert_eq!("6ddcf6da6ddcf6dx".parse::<VolumeId64>(), expected);
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            VolumeId64::try_from(crate::alloc::string::String::from("6ddcf6da6ddcf6dx")),
+            expected
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_base36() {
+        for v in [
+            VolumeId64::nil(),
+            VolumeId64::max(),
+            VolumeId64::parse("6ddcf6dafdc2fd38").unwrap(),
+        ] {
+            let encoded = v.to_base36();
+            assert_eq!(encoded.len(), 13);
+            assert_eq!(VolumeId64::from_base36_str(&encoded), Ok(v));
+            assert_eq!(VolumeId64::from_base36_str(&encoded.to_uppercase()), Ok(v));
+        }
+
+        assert_eq!(
+            VolumeId64::from_base36_str("zzzzzzzzzzzzz"),
+            Err(Error(ErrorKind::ParseOutOfRange))
+        );
+        assert_eq!(
+            VolumeId64::from_base36_str("00000000000-1"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '-',
+                index: 12,
+                expected: "[0-9a-zA-Z]",
+            }))
+        );
+        assert_eq!(
+            VolumeId64::from_base36_str("z"),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 1,
+                expected: 13,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_cstr() {
+        assert_eq!(
+            VolumeId64::parse_cstr(c"49aa648a49aa648a"),
+            VolumeId64::parse("49aa648a49aa648a")
+        );
+        assert_eq!(
+            VolumeId64::parse_cstr(c"49aa648a49aa648g"),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'g',
+                index: 16,
+            }))
+        );
+        assert_eq!(
+            VolumeId64::parse_cstr(c"\xff"),
+            Err(Error(ErrorKind::ParseInvalidUTF8))
+        );
+    }
+
+    #[test]
+    fn test_parse_overlong() {
+        assert_eq!(
+            VolumeId64::parse("a1a2a3a4a5a6a7a8a9"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 18 }))
+        );
+        assert_eq!(
+            VolumeId64::parse("a1a2a3a4a5a6a7a8a9b0b1b2"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 24 }))
+        );
+    }
+
+    #[test]
+    fn test_log_token() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+        let token = v.to_log_token();
+
+        assert_eq!(token, "vid64:a1a2a3a4a5a6a7a8");
+        assert_eq!(VolumeId64::from_log_token(&token), Ok(v));
+
+        assert_eq!(
+            VolumeId64::from_log_token("vid32:a1a2a3a4a5a6a7a8"),
+            Err(Error(ErrorKind::ParsePrefix { expected: "vid64:" }))
+        );
+        assert_eq!(
+            VolumeId64::from_log_token("a1a2a3a4a5a6a7a8"),
+            Err(Error(ErrorKind::ParsePrefix { expected: "vid64:" }))
+        );
+        assert_eq!(
+            VolumeId64::from_log_token("vid64:a1a2a3a4a5a6a7a"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 15 }))
+        );
+    }
+
+    #[test]
+    fn test_from_hexdump_fragment() {
+        let expected = VolumeId64::from_bytes([0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]);
+
+        assert_eq!(
+            VolumeId64::from_hexdump_fragment(
+                "00000040: 0000 0000 a1a2 a3a4 a5a6 a7a8 0000 0000  ................",
+                4
+            ),
+            Ok(expected)
+        );
+        assert_eq!(
+            VolumeId64::from_hexdump_fragment(
+                "00000040  00 00 00 00 a1 a2 a3 a4  a5 a6 a7 a8 00 00 00 00  |................|",
+                4
+            ),
+            Ok(expected)
+        );
+
+        assert_eq!(
+            VolumeId64::from_hexdump_fragment("00000040: 0000 2900", 2),
+            Err(Error(ErrorKind::HexdumpLength {
+                len: 4,
+                expected: 10,
+            }))
+        );
+        assert_eq!(
+            VolumeId64::from_hexdump_fragment("", 0),
+            Err(Error(ErrorKind::HexdumpLength {
+                len: 0,
+                expected: 8,
+            }))
+        );
+
+        // The text columns are valid hex, but aren't read as bytes.
+        assert_eq!(
+            VolumeId64::from_hexdump_fragment(
+                "00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef",
+                14
+            ),
+            Err(Error(ErrorKind::HexdumpLength {
+                len: 16,
+                expected: 22,
+            }))
+        );
+        assert_eq!(
+            VolumeId64::from_hexdump_fragment(
+                "00000000  30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66  0123 4567",
+                14
+            ),
+            Err(Error(ErrorKind::HexdumpLength {
+                len: 16,
+                expected: 22,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_unwrapped() {
+        let expected = Ok(VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap());
+
+        for input in [
+            "a1a2a3a4a5a6a7a8",
+            "{a1a2a3a4a5a6a7a8}",
+            "[a1a2a3a4a5a6a7a8]",
+            "(a1a2a3a4a5a6a7a8)",
+            "\"a1a2a3a4a5a6a7a8\"",
+            "'a1a2a3a4a5a6a7a8'",
+        ] {
+            assert_eq!(VolumeId64::parse_unwrapped(input), expected);
+        }
+
+        for input in [
+            "{a1a2a3a4a5a6a7a8]",
+            "{a1a2a3a4a5a6a7a8",
+            "a1a2a3a4a5a6a7a8}",
+            "[[a1a2a3a4a5a6a7a8]]",
+            "{\"a1a2a3a4a5a6a7a8\"}",
+            "{",
+        ] {
+            assert_eq!(
+                VolumeId64::parse_unwrapped(input),
+                Err(Error(ErrorKind::ParseWrapper))
+            );
+        }
+
+        assert_eq!(VolumeId64::parse_unwrapped("{}"), VolumeId64::parse(""));
+    }
+
+    #[test]
+    fn test_is_valid_str() {
+        const _: () = assert!(VolumeId64::is_valid_str("6ddcf6dafdc2fd38"));
+
+        assert!(VolumeId64::is_valid_str("6DDCF6DAFDC2FD38"));
+        assert!(!VolumeId64::is_valid_str("6ddcf6dafdc2fd3"));
+        assert!(!VolumeId64::is_valid_str("6ddc-f6da-fdc2-fd38"));
+        assert!(!VolumeId64::is_valid_str("6ddcf6dafdc2fd3g"));
+        assert!(!VolumeId64::is_valid_str(""));
+    }
+
+    #[test]
+    fn test_from_ascii_array() {
+        let ascii = *b"a1a2a3a4a5a6a7a8";
+
+        assert_eq!(
+            VolumeId64::from_ascii_array(&ascii).unwrap(),
+            VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap()
+        );
+        assert_ne!(
+            VolumeId64::from_ascii_array(&ascii).unwrap().as_bytes()[..],
+            ascii[..8]
+        );
+        assert_eq!(
+            VolumeId64::from_ascii_array(&ascii).unwrap().as_bytes(),
+            &[0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]
+        );
+
+        let mut invalid = ascii;
+        invalid[1] = b'g';
+        assert_eq!(
+            VolumeId64::from_ascii_array(&invalid),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'g',
+                index: 2,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_simpleid64_detailed() {
+        assert_eq!(
+            VolumeId64::from_log_token("vid64:a1é"),
+            Err(Error(ErrorKind::ParseChar {
+                character: 'é',
+                index: 3,
+            }))
+        );
+        assert_eq!(
+            VolumeId64::from_log_token("vid64:a1a2a3a4"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 8 }))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_os() {
+        use std::ffi::OsStr;
+
+        assert_eq!(
+            VolumeId64::parse_os(OsStr::new("49aa648a6ddcf6da")),
+            VolumeId64::parse("49aa648a6ddcf6da")
+        );
+        assert_eq!(
+            VolumeId64::parse_os(OsStr::new("49aa648a6ddcf6d")),
+            VolumeId64::parse("49aa648a6ddcf6d")
+        );
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn test_parse_os_invalid_utf8() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        assert_eq!(
+            VolumeId64::parse_os(OsStr::from_bytes(b"49aa648a6ddcf6d\xff")),
+            Err(Error(ErrorKind::ParseInvalidUTF8))
+        );
+    }
+
+    #[test]
+    fn test_parse_zero_padded() {
+        assert_eq!(
+            VolumeId64::parse_zero_padded("1a2a3a4a5a6a7a8"),
+            VolumeId64::parse("01a2a3a4a5a6a7a8")
+        );
+        assert_eq!(
+            VolumeId64::parse_zero_padded("01a2a3a4a5a6a7a8"),
+            VolumeId64::parse("01a2a3a4a5a6a7a8")
+        );
+        assert_eq!(VolumeId64::parse_zero_padded("0"), Ok(VolumeId64::nil()));
+        assert_eq!(
+            VolumeId64::parse_zero_padded("a"),
+            VolumeId64::parse("000000000000000a")
+        );
+
+        assert_eq!(
+            VolumeId64::parse_zero_padded("01a2a3a4a5a6a7a80"),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 17 }))
+        );
+        assert_eq!(
+            VolumeId64::parse_zero_padded(""),
+            Err(Error(ErrorKind::ParseSimpleLength { len: 0 }))
+        );
+        assert_eq!(
+            VolumeId64::parse_zero_padded("1a2-a4"),
+            Err(Error(ErrorKind::ParseChar {
+                character: '-',
+                index: 4,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_from_qr_token() {
+        assert_eq!(
+            VolumeId64::from_qr_token("6DDCF6DAFDC2FD38"),
+            VolumeId64::parse("6ddcf6dafdc2fd38")
+        );
+
+        assert_eq!(
+            VolumeId64::from_qr_token("6Ddcf6dafdc2fd38"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: 'd',
+                index: 3,
+                expected: "[0-9A-F]",
+            }))
+        );
+        assert_eq!(
+            VolumeId64::from_qr_token("6DDCF6DAFDC2FD3"),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 15,
+                expected: 16,
+            }))
+        );
+        assert!(VolumeId64::from_qr_token("6DDC-6DAFDC2FD38").is_err());
+    }
+
+    #[test]
+    fn test_from_base64url_str() {
+        assert_eq!(
+            VolumeId64::from_base64url_str("oaKjpKWmp6g"),
+            VolumeId64::parse("a1a2a3a4a5a6a7a8")
+        );
+        assert_eq!(
+            VolumeId64::from_base64url_str("__________8"),
+            Ok(VolumeId64::max())
+        );
+
+        assert_eq!(
+            VolumeId64::from_base64url_str("oa+jpKWmp6g"),
+            Err(Error(ErrorKind::ParseEncodedChar {
+                character: '+',
+                index: 3,
+                expected: "[A-Za-z0-9_-]",
+            }))
+        );
+        assert_eq!(
+            VolumeId64::from_base64url_str("oaKjpKWmp6g="),
+            Err(Error(ErrorKind::ParseEncodedLength {
+                len: 12,
+                expected: 11,
+            }))
+        );
+        assert_eq!(
+            VolumeId64::from_base64url_str("oaKjpKWmp6h"),
+            Err(Error(ErrorKind::ParseOutOfRange))
+        );
+    }
+
+    #[test]
+    fn test_volumeid64_parser() {
+        let mut parser = VolumeId64Parser::new();
+        assert!(parser.is_empty());
+
+        for c in "a1a2a3a4".chars() {
+            assert_eq!(parser.push(c), Ok(None));
+        }
+        assert_eq!(
+            parser.push('-'),
+            Err(Error(ErrorKind::ParseChar {
+                character: '-',
+                index: 9,
+            }))
+        );
+        assert_eq!(parser.len(), 8);
+
+        for c in "A5a6a7a".chars() {
+            assert_eq!(parser.push(c), Ok(None));
+        }
+        assert_eq!(
+            parser.push('8'),
+            VolumeId64::parse("a1a2a3a4a5a6a7a8").map(Some)
+        );
+        assert_eq!(
+            parser.push('0'),
+            Err(Error(ErrorKind::ParseChar {
+                character: '0',
+                index: 17,
+            }))
+        );
+
+        parser.reset();
+        assert!(parser.is_empty());
+        assert_eq!(parser, VolumeId64Parser::default());
+    }
+
+    #[test]
+    fn test_parse_kv() {
+        let v = VolumeId64::parse("a1a2a3a4a5a6a7a8").unwrap();
+
+        assert_eq!(
+            VolumeId64::parse_kv("serial=a1a2a3a4a5a6a7a8", "serial"),
+            Ok(v)
+        );
+        assert_eq!(
+            VolumeId64::parse_kv("serial = A1A2A3A4A5A6A7A8\n", "serial"),
+            Ok(v)
+        );
+
+        assert_eq!(
+            VolumeId64::parse_kv("label=a1a2a3a4a5a6a7a8", "serial"),
+            Err(Error(ErrorKind::ParseKey))
+        );
+        assert_eq!(
+            VolumeId64::parse_kv("serial a1a2a3a4a5a6a7a8", "serial"),
+            Err(Error(ErrorKind::ParseMissingDelimiter { delimiter: '=' }))
+        );
+        assert!(VolumeId64::parse_kv("serial=", "serial").is_err());
+    }
 }
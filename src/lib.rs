@@ -42,6 +42,14 @@ compile_error!("At least one of the features `id32` or `id64` must be enabled.")
 
 mod common;
 
+pub use crate::common::{
+    CharClass, Endianness, FormatKind, HexError, IdStr, InputClass, VolumeIdentifier,
+    classify_input, parse_id, try_parse_hex_detailed,
+};
+
+/// Helpers for on-disk structures of FAT-family filesystems.
+pub mod fs;
+
 #[cfg(feature = "id32")]
 /// 32-bit Volume ID used in FAT12/16/32 and exFAT filesystems.
 pub mod id32;